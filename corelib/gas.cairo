@@ -2,6 +2,9 @@
 extern type BuiltinCosts;
 extern type GasBuiltin;
 
+// TODO: Gas withdrawals are not inserted by the compiler - each `get_gas()` call is matched by
+//   its caller, which builds its own out-of-gas panic. Once withdrawals are inserted
+//   automatically, their failure branches should share a single out-of-gas block per function.
 extern fn get_gas() -> Option::<()> implicits(RangeCheck, GasBuiltin) nopanic;
 extern fn get_gas_all(
     costs: BuiltinCosts
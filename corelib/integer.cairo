@@ -30,12 +30,7 @@ extern fn u128_overflowing_sub(
     a: u128, b: u128
 ) -> Result::<u128, u128> implicits(RangeCheck) nopanic;
 
-fn u128_wrapping_add(a: u128, b: u128) -> u128 implicits(RangeCheck) nopanic {
-    match u128_overflowing_add(a, b) {
-        Result::Ok(x) => x,
-        Result::Err(x) => x,
-    }
-}
+extern fn u128_wrapping_add(a: u128, b: u128) -> u128 implicits(RangeCheck) nopanic;
 
 extern fn u128_wide_mul(a: u128, b: u128) -> (u128, u128) implicits(RangeCheck) nopanic;
 
@@ -177,6 +177,13 @@ use nullable::null;
 use nullable::into_nullable;
 use nullable::from_nullable;
 
+// Snapshots.
+mod snapshot;
+use snapshot::Snapshot;
+use snapshot::SnapshotCopy;
+use snapshot::SnapshotDrop;
+use snapshot::snapshot_take;
+
 // Arrays.
 mod array;
 use array::Array;
@@ -0,0 +1,5 @@
+extern type Snapshot<T>;
+impl SnapshotCopy<T> of Copy::<Snapshot::<T>>;
+impl SnapshotDrop<T> of Drop::<Snapshot::<T>>;
+
+extern fn snapshot_take<T>(value: T) -> (T, Snapshot::<T>) nopanic;
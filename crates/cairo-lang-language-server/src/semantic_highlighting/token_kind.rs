@@ -37,6 +37,7 @@ impl SemanticTokenKind {
             | SyntaxKind::TokenLE
             | SyntaxKind::TokenLT
             | SyntaxKind::TokenNot
+            | SyntaxKind::TokenAt
             | SyntaxKind::TokenPlus
             | SyntaxKind::TokenMinus
             | SyntaxKind::TokenMul
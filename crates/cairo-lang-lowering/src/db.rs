@@ -2,6 +2,7 @@ use std::collections::HashSet;
 use std::ops::Deref;
 use std::sync::Arc;
 
+use cairo_lang_debug::DebugWithDb;
 use cairo_lang_defs::ids::{FunctionWithBodyId, LanguageElementId, ModuleId, ModuleItemId};
 use cairo_lang_diagnostics::{Diagnostics, DiagnosticsBuilder, Maybe};
 use cairo_lang_filesystem::ids::FileId;
@@ -14,6 +15,7 @@ use semantic::items::functions::ConcreteFunctionWithBodyId;
 use crate::borrow_check::borrow_check;
 use crate::concretize::concretize_lowered;
 use crate::diagnostic::LoweringDiagnostic;
+use crate::fmt::LoweredFormatter;
 use crate::inline::{apply_inlining, PrivInlineData};
 use crate::lower::lower;
 use crate::panic::lower_panics;
@@ -101,6 +103,10 @@ pub trait LoweringGroup: SemanticGroup + Upcast<dyn SemanticGroup> {
     /// its signature and the functions it calls). The items in the returned vector are unique
     /// and the order is consistent, but not necessarily related to the order of the explicit
     /// implicits in the signature of the function.
+    ///
+    /// This is the order in which implicits are threaded - both as the function's parameters and
+    /// return values and at its call sites. Types in [Self::implicit_precedence] come first, in
+    /// that order, followed by the rest of the types sorted by name.
     #[salsa::invoke(crate::lower::implicits::function_with_body_all_implicits_vec)]
     fn function_with_body_all_implicits_vec(
         &self,
@@ -122,11 +128,28 @@ pub trait LoweringGroup: SemanticGroup + Upcast<dyn SemanticGroup> {
     /// An array that sets the precedence of implicit types.
     #[salsa::input]
     fn implicit_precedence(&self) -> Arc<Vec<TypeId>>;
+
+    /// The options for the lowering phase.
+    #[salsa::input]
+    fn lowering_options(&self) -> Arc<LoweringOptions>;
 }
 
 pub fn init_lowering_group(db: &mut (dyn LoweringGroup + 'static)) {
     // Initialize inputs.
     db.set_implicit_precedence(Arc::new(vec![]));
+    db.set_lowering_options(Arc::new(LoweringOptions::default()));
+}
+
+/// Options that control the lowering phase.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LoweringOptions {
+    /// The maximal number of statements that may be inlined into a single function.
+    /// Functions without an `inline` attribute are inlined as long as they fit in the budget;
+    /// once it is exhausted, the remaining calls are kept as regular calls.
+    /// `#[inline(always)]` functions ignore the budget, and `#[inline(never)]` ones are never
+    /// inlined.
+    /// `None` means there is no budget, and only `#[inline(always)]` functions are inlined.
+    pub inlining_budget: Option<usize>,
 }
 
 #[derive(Debug, Eq, PartialEq, Clone, Hash)]
@@ -144,9 +167,7 @@ fn priv_function_with_body_lowered_flat(
     function_id: FunctionWithBodyId,
 ) -> Maybe<Arc<FlatLowered>> {
     let structured = db.priv_function_with_body_lowered_structured(function_id)?;
-    let mut lowered = lower_panics(db, function_id, &structured)?;
-    borrow_check(function_id.module_file_id(db.upcast()), &mut lowered);
-    Ok(Arc::new(lowered))
+    Ok(Arc::new(apply_flat_passes(db, function_id, &structured, &mut |_, _| {})?))
 }
 
 fn priv_concrete_function_with_body_lowered_flat(
@@ -157,7 +178,7 @@ fn priv_concrete_function_with_body_lowered_flat(
     let mut lowered = (*db
         .priv_function_with_body_lowered_flat(function.function_with_body_id(semantic_db))?)
     .clone();
-    concretize_lowered(db, &mut lowered, &function.substitution(semantic_db)?);
+    apply_concretize_passes(db, function, &mut lowered, &mut |_, _| {})?;
     Ok(Arc::new(lowered))
 }
 
@@ -165,13 +186,84 @@ fn concrete_function_with_body_lowered(
     db: &dyn LoweringGroup,
     function: ConcreteFunctionWithBodyId,
 ) -> Maybe<Arc<FlatLowered>> {
-    let semantic_db = db.upcast();
     let mut lowered = (*db.priv_concrete_function_with_body_lowered_flat(function)?).clone();
+    apply_optimization_passes(db, function, &mut lowered, &mut |_, _| {})?;
+    Ok(Arc::new(lowered))
+}
+
+/// Lowers a structured function into a flat one, calling `on_stage` with the name of each pass
+/// and the lowering after it.
+fn apply_flat_passes(
+    db: &dyn LoweringGroup,
+    function_id: FunctionWithBodyId,
+    structured: &StructuredLowered,
+    on_stage: &mut dyn FnMut(&str, &FlatLowered),
+) -> Maybe<FlatLowered> {
+    let mut lowered = lower_panics(db, function_id, structured)?;
+    on_stage("lower_panics", &lowered);
+    borrow_check(function_id.module_file_id(db.upcast()), &mut lowered);
+    on_stage("borrow_check", &lowered);
+    Ok(lowered)
+}
 
+/// Specializes the flat lowering of a generic function to a concrete function, calling
+/// `on_stage` as [apply_flat_passes] does.
+fn apply_concretize_passes(
+    db: &dyn LoweringGroup,
+    function: ConcreteFunctionWithBodyId,
+    lowered: &mut FlatLowered,
+    on_stage: &mut dyn FnMut(&str, &FlatLowered),
+) -> Maybe<()> {
+    concretize_lowered(db, lowered, &function.substitution(db.upcast())?);
+    on_stage("concretize", lowered);
+    Ok(())
+}
+
+/// Optimizes the flat lowering of a concrete function, calling `on_stage` as
+/// [apply_flat_passes] does.
+fn apply_optimization_passes(
+    db: &dyn LoweringGroup,
+    function: ConcreteFunctionWithBodyId,
+    lowered: &mut FlatLowered,
+    on_stage: &mut dyn FnMut(&str, &FlatLowered),
+) -> Maybe<()> {
     // TODO(spapini): passing function.function_with_body_id might be weird here.
     // It's not really needed for inlining, so try to remove.
-    apply_inlining(db, function.function_with_body_id(semantic_db), &mut lowered)?;
-    Ok(Arc::new(lowered))
+    apply_inlining(db, function.function_with_body_id(db.upcast()), lowered)?;
+    on_stage("inlining", lowered);
+    // TODO: Add a constant folding pass after inlining. Comparisons of felt literals are folded
+    //   while lowering, but comparisons that only become constant after inlining are not.
+    Ok(())
+}
+
+/// Returns a rendering of the lowering of a concrete function after each of the lowering
+/// stages, as (stage name, rendered lowering) pairs. Used for debugging the lowering
+/// pipeline.
+///
+/// The stages are recomputed by the same pass functions the lowering queries use.
+pub fn concrete_function_with_body_lowering_stages(
+    db: &(dyn LoweringGroup + 'static),
+    function: ConcreteFunctionWithBodyId,
+) -> Maybe<Vec<(String, String)>> {
+    let function_id = function.function_with_body_id(db.upcast());
+    let structured = db.priv_function_with_body_lowered_structured(function_id)?;
+    let mut stages = vec![(
+        "structured".into(),
+        format!(
+            "{:?}",
+            structured.debug(&LoweredFormatter { db, variables: &structured.variables })
+        ),
+    )];
+    let mut on_stage = |name: &str, lowered: &FlatLowered| {
+        stages.push((
+            name.into(),
+            format!("{:?}", lowered.debug(&LoweredFormatter { db, variables: &lowered.variables })),
+        ));
+    };
+    let mut lowered = apply_flat_passes(db, function_id, &structured, &mut on_stage)?;
+    apply_concretize_passes(db, function, &mut lowered, &mut on_stage)?;
+    apply_optimization_passes(db, function, &mut lowered, &mut on_stage)?;
+    Ok(stages)
 }
 
 fn function_with_body_lowering_diagnostics(
@@ -4,6 +4,7 @@ use cairo_lang_diagnostics::{
     DiagnosticAdded, DiagnosticEntry, DiagnosticLocation, Diagnostics, DiagnosticsBuilder,
 };
 use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::TypeId;
 use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
 
 pub struct LoweringDiagnostics {
@@ -41,7 +42,7 @@ pub struct LoweringDiagnostic {
 impl DiagnosticEntry for LoweringDiagnostic {
     type DbType = dyn SemanticGroup;
 
-    fn format(&self, _db: &Self::DbType) -> String {
+    fn format(&self, db: &Self::DbType) -> String {
         match &self.kind {
             LoweringDiagnosticKind::Unreachable { .. } => "Unreachable code".into(),
             LoweringDiagnosticKind::NonZeroValueInMatch => {
@@ -52,6 +53,9 @@ impl DiagnosticEntry for LoweringDiagnostic {
             }
             LoweringDiagnosticKind::VariableMoved => "Variable was previously moved.".into(),
             LoweringDiagnosticKind::VariableNotDropped => "Variable not dropped.".into(),
+            LoweringDiagnosticKind::LiteralOutOfRange { ty } => {
+                format!("The value does not fit within the range of type {}.", ty.format(db))
+            }
             LoweringDiagnosticKind::UnsupportedMatch => "Unsupported match. Currently, matches \
                                                          require one arm per variant, in the \
                                                          order of variant definition."
@@ -108,6 +112,7 @@ pub enum LoweringDiagnosticKind {
     OnlyMatchZeroIsSupported,
     VariableMoved,
     VariableNotDropped,
+    LiteralOutOfRange { ty: TypeId },
     UnsupportedMatch,
     UnsupportedMatchArm,
     CannotInlineFunctionThatMightCallItself,
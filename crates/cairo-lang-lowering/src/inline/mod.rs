@@ -27,6 +27,7 @@ pub enum InlineConfiguration {
     // The user did not specify any inlining preferences.
     None,
     Always,
+    Never,
 }
 
 /// data about inlining.
@@ -154,6 +155,9 @@ fn parse_inline_attribute(
             [ast::Expr::Path(path)] if &path.node.get_text(db.upcast()) == "always" => {
                 config = InlineConfiguration::Always;
             }
+            [ast::Expr::Path(path)] if &path.node.get_text(db.upcast()) == "never" => {
+                config = InlineConfiguration::Never;
+            }
             [] => {
                 diagnostics.report(
                     attr.id_stable_ptr.untyped(),
@@ -199,6 +203,9 @@ pub struct FunctionInlinerRewriter<'db> {
     statement_rewrite_stack: StatementStack,
     /// Indicates that there was an error during inlining.
     inlining_failed: bool,
+    /// The number of statements that may still be inlined, or `None` if there is no limit.
+    /// See [LoweringOptions::inlining_budget](crate::db::LoweringOptions::inlining_budget).
+    inlining_budget: Option<usize>,
 }
 
 #[derive(Default)]
@@ -335,6 +342,7 @@ impl<'a, 'b> Mapper<'a, 'b> {
 impl<'db> FunctionInlinerRewriter<'db> {
     fn apply(ctx: LoweringContext<'db>, flat_lower: &FlatLowered) -> Maybe<FlatLowered> {
         let orig_root = flat_lower.root?;
+        let inlining_budget = ctx.db.lowering_options().inlining_budget;
         let mut rewriter = Self {
             ctx,
             block_queue: BlockQueue {
@@ -345,6 +353,7 @@ impl<'db> FunctionInlinerRewriter<'db> {
             block_end: FlatBlockEnd::Unreachable,
             statement_rewrite_stack: StatementStack::default(),
             inlining_failed: false,
+            inlining_budget,
         };
 
         rewriter.ctx.variables = flat_lower.variables.clone();
@@ -388,8 +397,8 @@ impl<'db> FunctionInlinerRewriter<'db> {
                     self.inlining_failed = true;
                 }
 
-                if inline_data.config == InlineConfiguration::Always
-                    && inline_data.info.is_inlineable
+                if inline_data.info.is_inlineable
+                    && self.should_inline(function_id, &inline_data)?
                 {
                     let optional_return_block_id = if inline_data.info.has_early_return {
                         // if the inlined function has an early return then we need to split the
@@ -427,6 +436,35 @@ impl<'db> FunctionInlinerRewriter<'db> {
         Ok(())
     }
 
+    /// Decides whether a call to the given inlineable function should be inlined, deducting the
+    /// size of the function from the inlining budget if so.
+    ///
+    /// `#[inline(always)]` functions are inlined regardless of the budget, and `#[inline(never)]`
+    /// functions are never inlined. Other functions are inlined only if a budget is set and they
+    /// fit in its remainder.
+    fn should_inline(
+        &mut self,
+        function_id: ConcreteFunctionWithBodyId,
+        inline_data: &PrivInlineData,
+    ) -> Maybe<bool> {
+        let force = match inline_data.config {
+            InlineConfiguration::Always => true,
+            InlineConfiguration::Never => return Ok(false),
+            InlineConfiguration::None => false,
+        };
+        let budget = match self.inlining_budget {
+            Some(budget) => budget,
+            None => return Ok(force),
+        };
+        let lowered = self.ctx.db.priv_concrete_function_with_body_lowered_flat(function_id)?;
+        let size: usize = lowered.blocks.iter().map(|(_, block)| block.statements.len()).sum();
+        if size > budget && !force {
+            return Ok(false);
+        }
+        self.inlining_budget = Some(budget.saturating_sub(size));
+        Ok(true)
+    }
+
     /// Inlines the given function, with the given input and outputs variables.
     /// The statements that needs to replace the call statement in the original block
     /// are pushed into the statement_rewrite_stack.
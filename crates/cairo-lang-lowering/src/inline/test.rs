@@ -1,12 +1,14 @@
+use std::sync::Arc;
+
 use cairo_lang_debug::DebugWithDb;
 use cairo_lang_plugins::get_default_plugins;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::test_utils::setup_test_function;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 
-use crate::db::LoweringGroup;
+use crate::db::{LoweringGroup, LoweringOptions};
 use crate::fmt::LoweredFormatter;
-use crate::inline::apply_inlining;
+use crate::inline::{apply_inlining, InlineConfiguration};
 use crate::test_utils::LoweringDatabaseForTesting;
 
 cairo_lang_test_utils::test_file_test!(
@@ -15,6 +17,7 @@ cairo_lang_test_utils::test_file_test!(
     {
 
         inline :"inline",
+        inline_budget :"inline_budget",
         inline_diagnostics :"inline_diagnostics",
     },
     test_function_inlining
@@ -25,6 +28,11 @@ fn test_function_inlining(
 ) -> OrderedHashMap<String, String> {
     let db = &mut LoweringDatabaseForTesting::default();
     db.set_semantic_plugins(get_default_plugins());
+    if let Some(inlining_budget) = inputs.get("inlining_budget") {
+        db.set_lowering_options(Arc::new(LoweringOptions {
+            inlining_budget: Some(inlining_budget.parse().unwrap()),
+        }));
+    }
     let (test_function, semantic_diagnostics) = setup_test_function(
         db,
         inputs["function"].as_str(),
@@ -52,3 +60,21 @@ fn test_function_inlining(
         ("lowering_diagnostics".into(), lowering_diagnostics.format(db)),
     ])
 }
+
+#[test]
+fn inline_always_attribute() {
+    let db = &mut LoweringDatabaseForTesting::default();
+    let test_function =
+        setup_test_function(db, "#[inline(always)]\nfn foo(a: felt) -> felt { a }", "foo", "")
+            .unwrap();
+
+    let attributes = db.function_with_body_attributes(test_function.function_id).unwrap();
+    assert_eq!(
+        format!("{:?}", attributes.debug(db)),
+        "[Attribute { id: \"inline\", args: [\"always\", ] }]"
+    );
+    assert_eq!(
+        db.priv_inline_data(test_function.function_id).unwrap().config,
+        InlineConfiguration::Always
+    );
+}
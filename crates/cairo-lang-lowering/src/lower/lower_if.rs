@@ -5,6 +5,7 @@ use cairo_lang_semantic as semantic;
 use cairo_lang_semantic::corelib;
 use cairo_lang_utils::extract_matches;
 use num_traits::Zero;
+use semantic::literals::reduce_felt_value;
 use semantic::TypeId;
 
 use super::context::{LoweredExpr, LoweringContext, LoweringFlowError, LoweringResult};
@@ -18,6 +19,7 @@ use crate::{Statement, StatementMatchEnum, StatementMatchExtern};
 #[allow(dead_code)]
 enum IfCondition {
     BoolExpr(semantic::ExprId),
+    Constant(bool),
     Eq(semantic::ExprId, semantic::ExprId),
 }
 
@@ -26,6 +28,18 @@ enum IfCondition {
 // TODO(lior): Make it an actual tree (handling && and ||).
 fn analyze_condition(ctx: &LoweringContext<'_>, expr_id: semantic::ExprId) -> IfCondition {
     let expr = &ctx.function_body.exprs[expr_id];
+    if let semantic::Expr::EnumVariantCtor(enum_expr) = expr {
+        // Only the unit-valued form (e.g. as produced by `true`/`false`) is constant; any other
+        // value expression may have side effects that must be lowered.
+        if is_unit_tuple(ctx, enum_expr.value_expr) {
+            if enum_expr.variant == corelib::true_variant(ctx.db.upcast()) {
+                return IfCondition::Constant(true);
+            }
+            if enum_expr.variant == corelib::false_variant(ctx.db.upcast()) {
+                return IfCondition::Constant(false);
+            }
+        }
+    };
     if let semantic::Expr::FunctionCall(function_call) = expr {
         if function_call.function == corelib::felt_eq(ctx.db.upcast())
             && function_call.args.len() == 2
@@ -37,11 +51,35 @@ fn analyze_condition(ctx: &LoweringContext<'_>, expr_id: semantic::ExprId) -> If
     IfCondition::BoolExpr(expr_id)
 }
 
+/// Returns whether two felt expressions are equal, if both of them are literals.
+pub fn literals_eq(
+    ctx: &LoweringContext<'_>,
+    expr_a: semantic::ExprId,
+    expr_b: semantic::ExprId,
+) -> Option<bool> {
+    match (&ctx.function_body.exprs[expr_a], &ctx.function_body.exprs[expr_b]) {
+        (semantic::Expr::Literal(literal_a), semantic::Expr::Literal(literal_b)) => {
+            Some(reduce_felt_value(&literal_a.value) == reduce_felt_value(&literal_b.value))
+        }
+        _ => None,
+    }
+}
+
+fn is_unit_tuple(ctx: &LoweringContext<'_>, expr_id: semantic::ExprId) -> bool {
+    let expr = &ctx.function_body.exprs[expr_id];
+    matches!(expr, semantic::Expr::Tuple(tuple) if tuple.items.is_empty())
+}
+
 fn is_zero(ctx: &LoweringContext<'_>, expr_id: semantic::ExprId) -> bool {
     let expr = &ctx.function_body.exprs[expr_id];
-    matches!(expr, semantic::Expr::Literal(literal) if literal.value.is_zero())
+    matches!(expr, semantic::Expr::Literal(literal) if reduce_felt_value(&literal.value).is_zero())
 }
 
+// TODO: Lower `while cond { body }` once loops are parsed, reusing the bool match of
+//   [lower_expr_if_bool]: a header block that matches on the condition, a true arm running the
+//   body and jumping back to the header, and a false arm exiting the loop with the unit value.
+//   An [IfCondition::Constant] condition should skip the header: `while false` emits nothing and
+//   `while true` jumps straight back into the body.
 /// Lowers an expression of type [semantic::ExprIf].
 pub fn lower_expr_if(
     ctx: &mut LoweringContext<'_>,
@@ -50,6 +88,7 @@ pub fn lower_expr_if(
 ) -> LoweringResult<LoweredExpr> {
     match analyze_condition(ctx, expr.condition) {
         IfCondition::BoolExpr(_) => lower_expr_if_bool(ctx, scope, expr),
+        IfCondition::Constant(value) => lower_expr_if_constant(ctx, scope, expr, value),
         IfCondition::Eq(expr_a, expr_b) => lower_expr_if_eq(ctx, scope, expr, expr_a, expr_b),
     }
 }
@@ -98,6 +137,25 @@ pub fn lower_expr_if_bool(
     merged.expr
 }
 
+/// Lowers an expression of type [semantic::ExprIf], for the case of [IfCondition::Constant].
+/// Only the taken branch is lowered, inline, without a match.
+pub fn lower_expr_if_constant(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockBuilder,
+    expr: &semantic::ExprIf,
+    value: bool,
+) -> LoweringResult<LoweredExpr> {
+    log::trace!("Lowering a constant if expression: {:?}", expr.debug(&ctx.expr_formatter));
+    match (value, expr.else_block) {
+        (true, _) => lower_expr(ctx, scope, expr.if_block),
+        (false, Some(else_block)) => lower_expr(ctx, scope, else_block),
+        (false, None) => Ok(LoweredExpr::Tuple {
+            exprs: vec![],
+            location: ctx.get_location(expr.stable_ptr.untyped()),
+        }),
+    }
+}
+
 /// Lowers an expression of type [semantic::ExprIf], for the case of [IfCondition::Eq].
 pub fn lower_expr_if_eq(
     ctx: &mut LoweringContext<'_>,
@@ -110,6 +168,9 @@ pub fn lower_expr_if_eq(
         "Started lowering of an if-eq-zero expression: {:?}",
         expr.debug(&ctx.expr_formatter)
     );
+    if let Some(value) = literals_eq(ctx, expr_a, expr_b) {
+        return lower_expr_if_constant(ctx, scope, expr, value);
+    }
     let if_location = ctx.get_location(expr.stable_ptr.untyped());
     let condition_var = if is_zero(ctx, expr_b) {
         lower_expr(ctx, scope, expr_a)?.var(ctx, scope)?
@@ -203,3 +264,64 @@ fn lower_optional_else_block(
         }
     }
 }
+
+/// Lowers an expression of type [semantic::ExprLogicalOperator].
+///
+/// `a && b` is lowered as `match a { false => false, true => b }`, and `a || b` is lowered as
+/// `match a { false => b, true => true }`, so that `b` is only evaluated when required.
+pub fn lower_expr_logical_operator(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockBuilder,
+    expr: &semantic::ExprLogicalOperator,
+) -> LoweringResult<LoweredExpr> {
+    log::trace!("Lowering a logical operator: {:?}", expr.debug(&ctx.expr_formatter));
+    let lhs_var = lower_expr(ctx, scope, expr.lhs)?.var(ctx, scope)?;
+    let semantic_db = ctx.db.upcast();
+    let unit_ty = corelib::unit_ty(semantic_db);
+    let location = ctx.get_location(expr.stable_ptr.untyped());
+    let rhs_location = ctx.get_location(ctx.function_body.exprs[expr.rhs].stable_ptr().untyped());
+
+    let (short_circuit_variant, rhs_variant) = match expr.op {
+        semantic::LogicalOperator::AndAnd => {
+            (corelib::false_variant(semantic_db), corelib::true_variant(semantic_db))
+        }
+        semantic::LogicalOperator::OrOr => {
+            (corelib::true_variant(semantic_db), corelib::false_variant(semantic_db))
+        }
+    };
+
+    // The arm in which the value of the expression is determined by the lhs.
+    let mut subscope_short_circuit = scope.subscope_with_bound_refs();
+    let unit_var = subscope_short_circuit.add_input(ctx, VarRequest { ty: unit_ty, location });
+    let short_circuit_var = generators::EnumConstruct {
+        input: unit_var,
+        variant: short_circuit_variant.clone(),
+        location,
+    }
+    .add(ctx, &mut subscope_short_circuit);
+    let block_short_circuit = lowered_expr_to_block_scope_end(
+        ctx,
+        subscope_short_circuit,
+        Ok(LoweredExpr::AtVariable(short_circuit_var)),
+    )
+    .map_err(LoweringFlowError::Failed)?;
+
+    // The arm in which the rhs is evaluated.
+    let mut subscope_rhs = scope.subscope_with_bound_refs();
+    subscope_rhs.add_input(ctx, VarRequest { ty: unit_ty, location: rhs_location });
+    let lowered_rhs = lower_expr(ctx, &mut subscope_rhs, expr.rhs);
+    let block_rhs = lowered_expr_to_block_scope_end(ctx, subscope_rhs, lowered_rhs)
+        .map_err(LoweringFlowError::Failed)?;
+
+    let merged = merge_sealed(ctx, scope, vec![block_short_circuit, block_rhs], location);
+
+    // Emit the statement. The arms are ordered by the variant index.
+    let mut arms = vec![(short_circuit_variant, merged.blocks[0]), (rhs_variant, merged.blocks[1])];
+    arms.sort_by_key(|(variant, _)| variant.idx);
+    scope.push_finalized_statement(Statement::MatchEnum(StatementMatchEnum {
+        concrete_enum_id: corelib::core_bool_enum(semantic_db),
+        input: lhs_var,
+        arms,
+    }));
+    merged.expr
+}
@@ -6,13 +6,16 @@ use cairo_lang_semantic as semantic;
 use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
 use cairo_lang_utils::{extract_matches, try_extract_matches};
 use itertools::{chain, zip_eq};
-use num_traits::Zero;
+use num_traits::{Signed, Zero};
 use scope::BlockBuilder;
 use semantic::corelib::{
-    core_felt_is_zero, core_felt_ty, core_nonzero_ty, get_core_function_id,
-    jump_nz_nonzero_variant, jump_nz_zero_variant, unit_ty,
+    core_felt_is_zero, core_felt_ty, core_nonzero_ty, core_snapshot_ty, false_variant, felt_eq,
+    get_core_function_id, get_core_ty_by_name, jump_nz_nonzero_variant, jump_nz_zero_variant,
+    true_variant, unit_ty, unwrap_snapshot_ty,
 };
+use semantic::db::SemanticGroup;
 use semantic::items::enm::SemanticEnumEx;
+use semantic::literals::reduce_felt_value;
 use semantic::{ConcreteTypeId, ExprPropagateError, TypeLongId};
 
 use self::context::{
@@ -20,7 +23,7 @@ use self::context::{
     LoweringFlowError,
 };
 use self::external::{extern_facade_expr, extern_facade_return_tys};
-use self::lower_if::lower_expr_if;
+use self::lower_if::{literals_eq, lower_expr_if, lower_expr_logical_operator};
 use self::scope::SealedBlockBuilder;
 use crate::db::LoweringGroup;
 use crate::diagnostic::LoweringDiagnosticKind::*;
@@ -36,6 +39,10 @@ mod lower_if;
 mod scope;
 
 /// Lowers a semantic free function.
+///
+/// Extern functions have no body and are never lowered. A function written without a body is
+/// reported as a parser diagnostic, so a failure to fetch the semantic body implies the reason was
+/// already reported as a semantic diagnostic.
 pub fn lower(db: &dyn LoweringGroup, function_id: FunctionWithBodyId) -> Maybe<StructuredLowered> {
     log::trace!("Lowering a free function.");
     let is_empty_semantic_diagnostics = db.function_declaration_diagnostics(function_id).is_empty()
@@ -115,6 +122,9 @@ fn lower_block(
     lowered_expr_to_block_scope_end(ctx, scope, block_expr)
 }
 
+// TODO: Nested blocks are inlined into the current block. There is no statement for calling a
+//   separate block whose end is [crate::StructuredBlockEnd::Callsite]; adding one would let
+//   blocks shared between contexts be lowered once instead of duplicating their statements.
 /// Lowers a semantic block.
 fn lower_expr_block(
     ctx: &mut LoweringContext<'_>,
@@ -303,6 +313,9 @@ fn lower_expr(
     expr_id: semantic::ExprId,
 ) -> LoweringResult<LoweredExpr> {
     let expr = &ctx.function_body.exprs[expr_id];
+    // TODO: Lower closures (e.g. `|a| a + c`) once they are parsed and have a semantic
+    //   expression: construct a struct of the captured variables, and call a generated free
+    //   function taking that struct as an extra parameter.
     match expr {
         semantic::Expr::Constant(expr) => lower_expr_constant(ctx, expr, scope),
         semantic::Expr::Tuple(expr) => lower_expr_tuple(ctx, expr, scope),
@@ -320,6 +333,8 @@ fn lower_expr(
         semantic::Expr::StructCtor(expr) => lower_expr_struct_ctor(ctx, expr, scope),
         semantic::Expr::EnumVariantCtor(expr) => lower_expr_enum_ctor(ctx, expr, scope),
         semantic::Expr::PropagateError(expr) => lower_expr_error_propagate(ctx, expr, scope),
+        semantic::Expr::LogicalOperator(expr) => lower_expr_logical_operator(ctx, scope, expr),
+        semantic::Expr::Snapshot(expr) => lower_expr_snapshot(ctx, expr, scope),
         semantic::Expr::Missing(semantic::ExprMissing { diag_added, .. }) => {
             Err(LoweringFlowError::Failed(*diag_added))
         }
@@ -333,11 +348,31 @@ fn lower_expr_literal(
 ) -> LoweringResult<LoweredExpr> {
     log::trace!("Lowering a literal: {:?}", expr.debug(&ctx.expr_formatter));
     let location = ctx.get_location(expr.stable_ptr.untyped());
+    if let Some(bits) = unsigned_integer_bits(ctx.db.upcast(), expr.ty) {
+        if expr.value.is_negative() || expr.value.bits() > bits {
+            return Err(LoweringFlowError::Failed(
+                ctx.diagnostics.report_by_location(location, LiteralOutOfRange { ty: expr.ty }),
+            ));
+        }
+    }
+    let value = if expr.ty == core_felt_ty(ctx.db.upcast()) {
+        reduce_felt_value(&expr.value)
+    } else {
+        expr.value.clone()
+    };
     Ok(LoweredExpr::AtVariable(
-        generators::Literal { value: expr.value.clone(), ty: expr.ty, location }.add(ctx, scope),
+        generators::Literal { value, ty: expr.ty, location }.add(ctx, scope),
     ))
 }
 
+/// Returns the number of bits of an unsigned integer type, or None if `ty` is not one.
+fn unsigned_integer_bits(db: &dyn SemanticGroup, ty: semantic::TypeId) -> Option<u64> {
+    [("u8", 8), ("u64", 64), ("u128", 128)]
+        .into_iter()
+        .find(|(name, _)| ty == get_core_ty_by_name(db, (*name).into(), vec![]))
+        .map(|(_, bits)| bits)
+}
+
 fn lower_expr_constant(
     ctx: &mut LoweringContext<'_>,
     expr: &semantic::ExprConstant,
@@ -377,6 +412,15 @@ fn lower_expr_function_call(
     log::trace!("Lowering a function call expression: {:?}", expr.debug(&ctx.expr_formatter));
     let location = ctx.get_location(expr.stable_ptr.untyped());
 
+    // Fold a comparison of two felt literals into the resulting bool.
+    if expr.function == felt_eq(ctx.db.upcast()) {
+        if let [arg_a, arg_b] = expr.args[..] {
+            if let Some(value) = literals_eq(ctx, arg_a, arg_b) {
+                return lower_bool_constant(ctx, scope, value, location);
+            }
+        }
+    }
+
     // TODO(spapini): Use the correct stable pointer.
     let arg_inputs = lower_exprs_as_vars(ctx, &expr.args, scope)?;
     let (ref_tys, ref_inputs): (_, Vec<VariableId>) = expr
@@ -759,6 +803,21 @@ fn lower_expr_enum_ctor(
     ))
 }
 
+/// Lowers a constant bool value as a construction of the matching `bool` variant.
+fn lower_bool_constant(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockBuilder,
+    value: bool,
+    location: StableLocation,
+) -> LoweringResult<LoweredExpr> {
+    let semantic_db = ctx.db.upcast();
+    let variant = if value { true_variant(semantic_db) } else { false_variant(semantic_db) };
+    let input = LoweredExpr::Tuple { exprs: vec![], location }.var(ctx, scope)?;
+    Ok(LoweredExpr::AtVariable(
+        generators::EnumConstruct { input, variant, location }.add(ctx, scope),
+    ))
+}
+
 /// Lowers an expression of type [semantic::ExprMemberAccess].
 fn lower_expr_member_access(
     ctx: &mut LoweringContext<'_>,
@@ -773,10 +832,22 @@ fn lower_expr_member_access(
         .position(|(_, member)| member.id == expr.member)
         .to_maybe()
         .map_err(LoweringFlowError::Failed)?;
+    let input = lower_expr(ctx, scope, expr.expr)?.var(ctx, scope)?;
+    // A snapshot of a struct is destructured into snapshots of its members.
+    let is_snapshot = unwrap_snapshot_ty(ctx.db.upcast(), ctx.variables[input].ty).is_some();
     Ok(LoweredExpr::AtVariable(
         generators::StructMemberAccess {
-            input: lower_expr(ctx, scope, expr.expr)?.var(ctx, scope)?,
-            member_tys: members.into_iter().map(|(_, member)| member.ty).collect(),
+            input,
+            member_tys: members
+                .into_iter()
+                .map(|(_, member)| {
+                    if is_snapshot {
+                        core_snapshot_ty(ctx.db.upcast(), member.ty)
+                    } else {
+                        member.ty
+                    }
+                })
+                .collect(),
             member_idx,
             location,
         }
@@ -807,6 +878,42 @@ fn lower_expr_struct_ctor(
     ))
 }
 
+/// Lowers an expression of type [semantic::ExprSnapshot].
+/// The snapshot is taken using `snapshot_take`, which also returns the original value. If the
+/// snapshot is of a variable, the variable is rebound to the returned value, so it remains usable.
+fn lower_expr_snapshot(
+    ctx: &mut LoweringContext<'_>,
+    expr: &semantic::ExprSnapshot,
+    scope: &mut BlockBuilder,
+) -> LoweringResult<LoweredExpr> {
+    log::trace!("Lowering a snapshot expression: {:?}", expr.debug(&ctx.expr_formatter));
+    let location = ctx.get_location(expr.stable_ptr.untyped());
+    let input = lower_expr(ctx, scope, expr.inner)?.var(ctx, scope)?;
+    let inner_ty = ctx.variables[input].ty;
+    let call_result = generators::Call {
+        function: get_core_function_id(
+            ctx.db.upcast(),
+            "snapshot_take".into(),
+            vec![semantic::GenericArgumentId::Type(inner_ty)],
+        ),
+        inputs: vec![input],
+        ref_tys: vec![],
+        ret_tys: vec![inner_ty, expr.ty],
+        location,
+    }
+    .add(ctx, scope);
+    let [original, snapshot] = <[_; 2]>::try_from(call_result.returns).ok().unwrap();
+
+    // Rebind the variable the snapshot was taken of.
+    if let semantic::Expr::Var(semantic::ExprVar { var, .. }) = &ctx.function_body.exprs[expr.inner]
+    {
+        scope.put_semantic(ctx, *var, original);
+    }
+    scope.finalize_statement();
+
+    Ok(LoweredExpr::AtVariable(snapshot))
+}
+
 /// Lowers an expression of type [semantic::ExprPropagateError].
 fn lower_expr_error_propagate(
     ctx: &mut LoweringContext<'_>,
@@ -958,6 +1065,8 @@ fn lower_expr_assignment(
         expr.debug(&ctx.expr_formatter)
     );
     let location = ctx.get_location(expr.stable_ptr.untyped());
+    // TODO: Lower index-assignment targets (`arr[i] = v`) once index expressions and fixed-size
+    //   arrays exist. Until then, the target of an assignment is always a variable.
     let var = lower_expr(ctx, scope, expr.rhs)?.var(ctx, scope)?;
     scope.put_semantic(ctx, expr.var, var);
     Ok(LoweredExpr::Tuple { exprs: vec![], location })
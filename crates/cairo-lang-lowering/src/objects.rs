@@ -10,6 +10,7 @@ use cairo_lang_diagnostics::{Diagnostics, Maybe};
 use cairo_lang_semantic as semantic;
 use cairo_lang_semantic::{ConcreteEnumId, ConcreteVariant};
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
 use id_arena::{Arena, Id};
 use itertools::chain;
 use num_bigint::BigInt;
@@ -37,7 +38,12 @@ pub struct StructuredLowered {
     pub blocks: StructuredBlocks,
 }
 
+// TODO: Support serializing this for an on-disk cache. Variables and statements refer to interned
+//   ids (types, functions, locations) that are only valid within the database that created them,
+//   so these need a stable representation first.
 /// A lowered function code using flat blocks.
+/// The attributes of the function (e.g. `#[inline(always)]`) are not copied here - they are
+/// available from [cairo_lang_semantic::db::SemanticGroup::function_with_body_attributes].
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct FlatLowered {
     /// Diagnostics produced while lowering.
@@ -49,6 +55,266 @@ pub struct FlatLowered {
     /// Arena of allocated lowered blocks.
     pub blocks: FlatBlocks,
 }
+impl FlatLowered {
+    /// Returns the enums matched by a [StatementMatchEnum] in the blocks reachable from the root,
+    /// without duplicates.
+    pub fn matched_enums(&self) -> Maybe<Vec<ConcreteEnumId>> {
+        let mut matched_enums = OrderedHashSet::<ConcreteEnumId>::default();
+        let mut visited = OrderedHashSet::<BlockId>::default();
+        let mut stack = vec![self.root?];
+        while let Some(block_id) = stack.pop() {
+            if !visited.insert(block_id) {
+                continue;
+            }
+            let block = &self.blocks[block_id];
+            for statement in &block.statements {
+                if let Statement::MatchEnum(StatementMatchEnum { concrete_enum_id, .. }) = statement
+                {
+                    matched_enums.insert(*concrete_enum_id);
+                }
+            }
+            stack.extend(block.successors());
+        }
+        Ok(matched_enums.into_iter().collect())
+    }
+
+    /// Returns the predecessors of each block reachable from the root, following match arms,
+    /// [FlatBlockEnd::Fallthrough] and [FlatBlockEnd::Goto]. The root is always included. A valid
+    /// lowering never jumps back to the root, so its predecessors are the edges reported by
+    /// [Self::edges_into_root].
+    ///
+    /// Note that an arm ending with [FlatBlockEnd::Callsite] merges back into the statements
+    /// following the match in its predecessor, rather than into a separate block.
+    pub fn predecessors(&self) -> Maybe<OrderedHashMap<BlockId, Vec<BlockId>>> {
+        let root = self.root?;
+        let mut predecessors = OrderedHashMap::<BlockId, Vec<BlockId>>::default();
+        predecessors.insert(root, vec![]);
+        let mut visited = OrderedHashSet::<BlockId>::default();
+        let mut stack = vec![root];
+        while let Some(block_id) = stack.pop() {
+            if !visited.insert(block_id) {
+                continue;
+            }
+            for successor in self.blocks[block_id].successors() {
+                predecessors.entry(successor).or_default().push(block_id);
+                stack.push(successor);
+            }
+        }
+        Ok(predecessors)
+    }
+
+    /// Returns the edges, as (source, root) pairs, of the blocks reachable from the root that jump
+    /// back into it. These are invalid, as the root is the entry of the function.
+    pub fn edges_into_root(&self) -> Maybe<Vec<(BlockId, BlockId)>> {
+        let root = self.root?;
+        Ok(self.predecessors()?[root].iter().map(|predecessor| (*predecessor, root)).collect())
+    }
+
+    /// Returns, for each value returned by the function, the index of the root block input it is
+    /// passed through from unchanged - i.e. the input every reachable [FlatBlockEnd::Return]
+    /// returns at that position - or None if the value may be modified.
+    /// This is the case for implicits and ref params that the function does not change.
+    ///
+    /// A variable merged by the remappings of match arms ending with [FlatBlockEnd::Callsite], or
+    /// by [FlatBlockEnd::Fallthrough] and [FlatBlockEnd::Goto], is passed through from an input if
+    /// every remapping into it is.
+    pub fn passthrough_outputs(&self) -> Maybe<Vec<Option<usize>>> {
+        let root = self.root?;
+        let mut analyzer = PassthroughAnalyzer {
+            lowered: self,
+            aliases: self.blocks[root]
+                .inputs
+                .iter()
+                .enumerate()
+                .map(|(input_idx, var)| (*var, Some(input_idx)))
+                .collect(),
+            passthrough_outputs: None,
+        };
+        analyzer.analyze_block(root);
+        Ok(analyzer.passthrough_outputs.unwrap_or_default())
+    }
+
+    /// Checks that, along the flow from the root, every variable is defined before it is used and
+    /// is defined only once. Returns a description of each violation.
+    ///
+    /// Variables defined in a match arm are visible only in that arm; the variables remapped by
+    /// arms ending with [FlatBlockEnd::Callsite] are defined by the match itself.
+    pub fn validate_definitions(&self) -> Maybe<Vec<String>> {
+        let mut validator =
+            DefinitionsValidator { lowered: self, all_defined: Default::default(), errors: vec![] };
+        validator.validate_block(self.root?, OrderedHashSet::default());
+        Ok(validator.errors)
+    }
+
+    /// Returns the largest number of arms of a [StatementMatchEnum] or [StatementMatchExtern] in
+    /// the function, or 0 if it has no matches.
+    pub fn max_match_arity(&self) -> usize {
+        self.blocks
+            .iter()
+            .flat_map(|(_, block)| &block.statements)
+            .filter_map(|statement| match statement {
+                Statement::MatchEnum(StatementMatchEnum { arms, .. }) => Some(arms.len()),
+                Statement::MatchExtern(StatementMatchExtern { arms, .. }) => Some(arms.len()),
+                _ => None,
+            })
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// Helper for [FlatLowered::passthrough_outputs].
+struct PassthroughAnalyzer<'a> {
+    lowered: &'a FlatLowered,
+    /// The root input each variable is known to be equal to, or None if it may differ from all
+    /// of them.
+    aliases: OrderedHashMap<VariableId, Option<usize>>,
+    passthrough_outputs: Option<Vec<Option<usize>>>,
+}
+impl<'a> PassthroughAnalyzer<'a> {
+    /// Analyzes a block and the blocks it flows into.
+    fn analyze_block(&mut self, block_id: BlockId) {
+        let block = &self.lowered.blocks[block_id];
+        for statement in &block.statements {
+            match statement {
+                Statement::MatchEnum(StatementMatchEnum { arms, .. }) => {
+                    for (_, arm_block_id) in arms {
+                        self.analyze_block(*arm_block_id);
+                    }
+                }
+                Statement::MatchExtern(StatementMatchExtern { arms, .. }) => {
+                    for (_, arm_block_id) in arms {
+                        self.analyze_block(*arm_block_id);
+                    }
+                }
+                _ => {}
+            }
+        }
+        match &block.end {
+            FlatBlockEnd::Callsite(remapping) | FlatBlockEnd::Goto(_, remapping) => {
+                self.merge_remapping(remapping);
+            }
+            FlatBlockEnd::Fallthrough(target_block_id, remapping) => {
+                self.merge_remapping(remapping);
+                self.analyze_block(*target_block_id);
+            }
+            FlatBlockEnd::Return(returns) => {
+                let current = returns.iter().map(|var| self.alias(*var)).collect::<Vec<_>>();
+                self.passthrough_outputs = Some(match self.passthrough_outputs.take() {
+                    None => current,
+                    Some(prev) => prev
+                        .into_iter()
+                        .zip(current)
+                        .map(|(prev, current)| if prev == current { prev } else { None })
+                        .collect(),
+                });
+            }
+            FlatBlockEnd::Unreachable => {}
+        }
+    }
+
+    /// Merges the inputs aliased by the sources of a remapping into its destinations. A
+    /// destination keeps its alias only if all the remappings into it agree on it.
+    fn merge_remapping(&mut self, remapping: &VarRemapping) {
+        for (dst, src) in remapping.iter() {
+            let src_alias = self.alias(*src);
+            match self.aliases.get(dst) {
+                Some(dst_alias) if *dst_alias != src_alias => {
+                    self.aliases.insert(*dst, None);
+                }
+                Some(_) => {}
+                None => {
+                    self.aliases.insert(*dst, src_alias);
+                }
+            }
+        }
+    }
+
+    /// Returns the root input a variable is known to be equal to.
+    fn alias(&self, var: VariableId) -> Option<usize> {
+        self.aliases.get(&var).copied().flatten()
+    }
+}
+
+/// Helper for [FlatLowered::validate_definitions].
+struct DefinitionsValidator<'a> {
+    lowered: &'a FlatLowered,
+    /// The variables defined so far anywhere in the function.
+    all_defined: OrderedHashSet<VariableId>,
+    errors: Vec<String>,
+}
+impl<'a> DefinitionsValidator<'a> {
+    /// Validates a block and the blocks it flows into, given the variables visible at its start.
+    fn validate_block(&mut self, block_id: BlockId, mut visible: OrderedHashSet<VariableId>) {
+        let block = &self.lowered.blocks[block_id];
+        self.define(&mut visible, block.inputs.iter().copied());
+        for statement in &block.statements {
+            self.check_used(block_id, &visible, statement.inputs());
+            let arms: Vec<BlockId> = match statement {
+                Statement::MatchEnum(StatementMatchEnum { arms, .. }) => {
+                    arms.iter().map(|(_, arm_block_id)| *arm_block_id).collect()
+                }
+                Statement::MatchExtern(StatementMatchExtern { arms, .. }) => {
+                    arms.iter().map(|(_, arm_block_id)| *arm_block_id).collect()
+                }
+                _ => vec![],
+            };
+            let mut match_outputs = OrderedHashSet::<VariableId>::default();
+            for arm in arms {
+                self.validate_block(arm, visible.clone());
+                if let FlatBlockEnd::Callsite(remapping) = &self.lowered.blocks[arm].end {
+                    match_outputs.extend(remapping.keys().copied());
+                }
+            }
+            self.define(&mut visible, chain!(match_outputs, statement.outputs()));
+        }
+        match &block.end {
+            FlatBlockEnd::Callsite(remapping) | FlatBlockEnd::Goto(_, remapping) => {
+                self.check_used(block_id, &visible, remapping.values().copied());
+            }
+            FlatBlockEnd::Return(returns) => {
+                self.check_used(block_id, &visible, returns.iter().copied());
+            }
+            FlatBlockEnd::Fallthrough(target_block_id, remapping) => {
+                self.check_used(block_id, &visible, remapping.values().copied());
+                self.define(&mut visible, remapping.keys().copied());
+                self.validate_block(*target_block_id, visible);
+            }
+            FlatBlockEnd::Unreachable => {}
+        }
+    }
+
+    /// Marks variables as defined and visible, reporting the ones that were already defined.
+    fn define(
+        &mut self,
+        visible: &mut OrderedHashSet<VariableId>,
+        vars: impl IntoIterator<Item = VariableId>,
+    ) {
+        for var in vars {
+            if !self.all_defined.insert(var) {
+                self.errors.push(format!("v{} is defined more than once.", var.index()));
+            }
+            visible.insert(var);
+        }
+    }
+
+    /// Reports the variables used in a block that are not visible.
+    fn check_used(
+        &mut self,
+        block_id: BlockId,
+        visible: &OrderedHashSet<VariableId>,
+        vars: impl IntoIterator<Item = VariableId>,
+    ) {
+        for var in vars {
+            if !visible.contains(&var) {
+                self.errors.push(format!(
+                    "v{} is used in blk{} before it is defined.",
+                    var.index(),
+                    block_id.0
+                ));
+            }
+        }
+    }
+}
 
 /// A block of statements. Each block gets inputs and outputs, and is composed of
 /// a linear sequence of statements.
@@ -120,6 +386,48 @@ pub struct FlatBlock {
     /// Describes how this block ends: returns to the caller or exits the function.
     pub end: FlatBlockEnd,
 }
+impl FlatBlock {
+    /// Returns the blocks the flow may continue to from this block: the arms of its match
+    /// statements, in order, followed by the target of a [FlatBlockEnd::Fallthrough] or
+    /// [FlatBlockEnd::Goto] end.
+    pub fn successors(&self) -> Vec<BlockId> {
+        let mut successors = vec![];
+        for statement in &self.statements {
+            match statement {
+                Statement::MatchEnum(StatementMatchEnum { arms, .. }) => {
+                    successors.extend(arms.iter().map(|(_, arm_block_id)| *arm_block_id));
+                }
+                Statement::MatchExtern(StatementMatchExtern { arms, .. }) => {
+                    successors.extend(arms.iter().map(|(_, arm_block_id)| *arm_block_id));
+                }
+                _ => {}
+            }
+        }
+        match &self.end {
+            FlatBlockEnd::Fallthrough(target_block_id, _)
+            | FlatBlockEnd::Goto(target_block_id, _) => successors.push(*target_block_id),
+            FlatBlockEnd::Callsite(_) | FlatBlockEnd::Return(_) | FlatBlockEnd::Unreachable => {}
+        }
+        successors
+    }
+
+    /// Replaces the statement at `index` with `statement`, and returns the replaced statement.
+    /// The new statement must have the same outputs, so that later uses of them remain valid.
+    pub fn replace_statement(&mut self, index: usize, statement: Statement) -> Statement {
+        assert_eq!(
+            statement.outputs(),
+            self.statements[index].outputs(),
+            "A replacing statement must have the same outputs."
+        );
+        std::mem::replace(&mut self.statements[index], statement)
+    }
+
+    /// Removes the statement at `index`, and returns it.
+    /// The outputs of the removed statement must not be used later on.
+    pub fn remove_statement(&mut self, index: usize) -> Statement {
+        self.statements.remove(index)
+    }
+}
 
 /// Describes what happens to the program flow at the end of a [`FlatBlock`].
 #[derive(Clone, Debug, PartialEq, Eq)]
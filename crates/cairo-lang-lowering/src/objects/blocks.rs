@@ -3,7 +3,7 @@ use std::ops::{Index, IndexMut};
 use super::StructuredBlock;
 use crate::FlatBlock;
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct BlockId(pub usize);
 
 /// A convenient wrapper around a vector of blocks.
@@ -23,6 +23,17 @@ impl<T> Blocks<T> {
     pub fn iter(&self) -> BlocksIter<'_, T> {
         self.into_iter()
     }
+    /// Returns the given blocks in increasing id order, without duplicates, regardless of the
+    /// order in which they are referenced.
+    pub fn iter_in_id_order(
+        &self,
+        block_ids: impl IntoIterator<Item = BlockId>,
+    ) -> impl Iterator<Item = (BlockId, &T)> {
+        let mut block_ids: Vec<_> = block_ids.into_iter().collect();
+        block_ids.sort();
+        block_ids.dedup();
+        block_ids.into_iter().map(|block_id| (block_id, &self[block_id]))
+    }
 
     pub fn len(&self) -> usize {
         self.0.len()
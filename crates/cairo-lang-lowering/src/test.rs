@@ -1,12 +1,22 @@
+use std::sync::Arc;
+
 use cairo_lang_debug::DebugWithDb;
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::{FunctionWithBodyId, ModuleItemId, TopLevelLanguageElementId};
 use cairo_lang_plugins::get_default_plugins;
+use cairo_lang_semantic::corelib::{core_module, get_core_ty_by_name};
 use cairo_lang_semantic::db::SemanticGroup;
-use cairo_lang_semantic::test_utils::setup_test_function;
+use cairo_lang_semantic::test_utils::{setup_test_function, TestFunction};
+use cairo_lang_utils::extract_matches;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use indoc::indoc;
+use itertools::{chain, Itertools};
 
-use crate::db::LoweringGroup;
+use crate::db::{concrete_function_with_body_lowering_stages, LoweringGroup};
 use crate::fmt::LoweredFormatter;
+use crate::objects::blocks::{BlockId, Blocks};
 use crate::test_utils::LoweringDatabaseForTesting;
+use crate::{FlatBlockEnd, FlatLowered, Statement, StatementLiteral, VarRemapping};
 
 cairo_lang_test_utils::test_file_test!(
     lowering,
@@ -22,9 +32,11 @@ cairo_lang_test_utils::test_file_test!(
         extern_ :"extern",
         arm_pattern_destructure :"arm_pattern_destructure",
         if_ :"if",
+        logical_operator :"logical_operator",
         match_ :"match",
         panic :"panic",
         rebindings :"rebindings",
+        snapshot :"snapshot",
         struct_ :"struct",
         tests :"tests",
         tuple :"tuple",
@@ -37,17 +49,9 @@ fn test_function_lowering(
 ) -> OrderedHashMap<String, String> {
     let db = &mut LoweringDatabaseForTesting::default();
     db.set_semantic_plugins(get_default_plugins());
-    let (test_function, semantic_diagnostics) = setup_test_function(
-        db,
-        inputs["function"].as_str(),
-        inputs["function_name"].as_str(),
-        inputs["module_code"].as_str(),
-    )
-    .split();
+    let (test_function, lowered, semantic_diagnostics) = lower_test_function(db, inputs);
     let structured_lowered =
         db.priv_function_with_body_lowered_structured(test_function.function_id).unwrap();
-    let lowered =
-        db.concrete_function_with_body_lowered(test_function.concrete_function_id).unwrap();
     let diagnostics =
         db.function_with_body_lowering_diagnostics(test_function.function_id).unwrap();
 
@@ -62,3 +66,514 @@ fn test_function_lowering(
         ("lowering_flat".into(), format!("{:?}", lowered.debug(&lowered_formatter))),
     ])
 }
+
+/// Sets up the test function described by the inputs of a test file, and returns it with its
+/// concrete lowering and the semantic diagnostics.
+fn lower_test_function(
+    db: &mut LoweringDatabaseForTesting,
+    inputs: &OrderedHashMap<String, String>,
+) -> (TestFunction, Arc<FlatLowered>, String) {
+    let (test_function, semantic_diagnostics) = setup_test_function(
+        db,
+        inputs["function"].as_str(),
+        inputs["function_name"].as_str(),
+        inputs["module_code"].as_str(),
+    )
+    .split();
+    let lowered =
+        db.concrete_function_with_body_lowered(test_function.concrete_function_id).unwrap();
+    (test_function, lowered, semantic_diagnostics)
+}
+
+cairo_lang_test_utils::test_file_test!(
+    matched_enums,
+    "src/test_data",
+    {
+        matched_enums :"matched_enums",
+    },
+    test_matched_enums
+);
+
+fn test_matched_enums(inputs: &OrderedHashMap<String, String>) -> OrderedHashMap<String, String> {
+    let db = &mut LoweringDatabaseForTesting::default();
+    let (_, lowered, semantic_diagnostics) = lower_test_function(db, inputs);
+    let matched_enums = lowered
+        .matched_enums()
+        .unwrap()
+        .into_iter()
+        .map(|concrete_enum_id| concrete_enum_id.enum_id(db).full_path(db))
+        .join("\n");
+
+    OrderedHashMap::from([
+        ("semantic_diagnostics".into(), semantic_diagnostics),
+        ("matched_enums".into(), matched_enums),
+    ])
+}
+
+cairo_lang_test_utils::test_file_test!(
+    max_match_arity,
+    "src/test_data",
+    {
+        max_match_arity :"max_match_arity",
+    },
+    test_max_match_arity
+);
+
+fn test_max_match_arity(inputs: &OrderedHashMap<String, String>) -> OrderedHashMap<String, String> {
+    let db = &mut LoweringDatabaseForTesting::default();
+    let (_, lowered, semantic_diagnostics) = lower_test_function(db, inputs);
+
+    OrderedHashMap::from([
+        ("semantic_diagnostics".into(), semantic_diagnostics),
+        ("max_match_arity".into(), lowered.max_match_arity().to_string()),
+    ])
+}
+
+cairo_lang_test_utils::test_file_test!(
+    implicits,
+    "src/test_data",
+    {
+        implicits :"implicits",
+    },
+    test_function_implicits
+);
+
+fn test_function_implicits(
+    inputs: &OrderedHashMap<String, String>,
+) -> OrderedHashMap<String, String> {
+    let db = &mut LoweringDatabaseForTesting::default();
+    let precedence = inputs["implicit_precedence"]
+        .split(", ")
+        .map(|name| get_core_ty_by_name(db, name.into(), vec![]))
+        .collect();
+    db.set_implicit_precedence(Arc::new(precedence));
+    let (test_function, lowered, semantic_diagnostics) = lower_test_function(db, inputs);
+    let implicits = db.function_with_body_all_implicits_vec(test_function.function_id).unwrap();
+
+    let lowered_formatter = LoweredFormatter { db, variables: &lowered.variables };
+    OrderedHashMap::from([
+        ("semantic_diagnostics".into(), semantic_diagnostics),
+        ("implicits".into(), implicits.into_iter().map(|ty| ty.format(db)).join(", ")),
+        ("lowering_flat".into(), format!("{:?}", lowered.debug(&lowered_formatter))),
+    ])
+}
+
+cairo_lang_test_utils::test_file_test!(
+    predecessors,
+    "src/test_data",
+    {
+        predecessors :"predecessors",
+    },
+    test_predecessors
+);
+
+fn test_predecessors(inputs: &OrderedHashMap<String, String>) -> OrderedHashMap<String, String> {
+    let db = &mut LoweringDatabaseForTesting::default();
+    let (_, lowered, semantic_diagnostics) = lower_test_function(db, inputs);
+    let predecessors = lowered
+        .predecessors()
+        .unwrap()
+        .iter()
+        .sorted_by_key(|(block_id, _)| block_id.0)
+        .map(|(block_id, predecessors)| {
+            chain!(
+                [format!("blk{}:", block_id.0)],
+                predecessors.iter().map(|predecessor| format!("blk{}", predecessor.0))
+            )
+            .join(" ")
+        })
+        .join("\n");
+
+    let lowered_formatter = LoweredFormatter { db, variables: &lowered.variables };
+    OrderedHashMap::from([
+        ("semantic_diagnostics".into(), semantic_diagnostics),
+        ("lowering_flat".into(), format!("{:?}", lowered.debug(&lowered_formatter))),
+        ("predecessors".into(), predecessors),
+    ])
+}
+
+cairo_lang_test_utils::test_file_test!(
+    successors,
+    "src/test_data",
+    {
+        successors :"successors",
+    },
+    test_successors
+);
+
+fn test_successors(inputs: &OrderedHashMap<String, String>) -> OrderedHashMap<String, String> {
+    let db = &mut LoweringDatabaseForTesting::default();
+    let (_, lowered, semantic_diagnostics) = lower_test_function(db, inputs);
+    let successors = lowered
+        .blocks
+        .iter()
+        .map(|(block_id, block)| {
+            chain!(
+                [format!("blk{}:", block_id.0)],
+                block.successors().into_iter().map(|successor| format!("blk{}", successor.0))
+            )
+            .join(" ")
+        })
+        .join("\n");
+
+    let lowered_formatter = LoweredFormatter { db, variables: &lowered.variables };
+    OrderedHashMap::from([
+        ("semantic_diagnostics".into(), semantic_diagnostics),
+        ("lowering_flat".into(), format!("{:?}", lowered.debug(&lowered_formatter))),
+        ("successors".into(), successors),
+    ])
+}
+
+cairo_lang_test_utils::test_file_test!(
+    passthrough_outputs,
+    "src/test_data",
+    {
+        passthrough_outputs :"passthrough_outputs",
+    },
+    test_passthrough_outputs
+);
+
+fn test_passthrough_outputs(
+    inputs: &OrderedHashMap<String, String>,
+) -> OrderedHashMap<String, String> {
+    let db = &mut LoweringDatabaseForTesting::default();
+    let (_, lowered, semantic_diagnostics) = lower_test_function(db, inputs);
+    let passthrough_outputs = lowered
+        .passthrough_outputs()
+        .unwrap()
+        .into_iter()
+        .map(|input_idx| match input_idx {
+            Some(input_idx) => input_idx.to_string(),
+            None => "_".into(),
+        })
+        .join(", ");
+
+    let lowered_formatter = LoweredFormatter { db, variables: &lowered.variables };
+    OrderedHashMap::from([
+        ("semantic_diagnostics".into(), semantic_diagnostics),
+        ("lowering_flat".into(), format!("{:?}", lowered.debug(&lowered_formatter))),
+        ("passthrough_outputs".into(), passthrough_outputs),
+    ])
+}
+
+cairo_lang_test_utils::test_file_test!(
+    lowering_stages,
+    "src/test_data",
+    {
+        lowering_stages :"lowering_stages",
+    },
+    test_lowering_stages
+);
+
+fn test_lowering_stages(inputs: &OrderedHashMap<String, String>) -> OrderedHashMap<String, String> {
+    let db = &mut LoweringDatabaseForTesting::default();
+    let (test_function, _, semantic_diagnostics) = lower_test_function(db, inputs);
+    let stages =
+        concrete_function_with_body_lowering_stages(db, test_function.concrete_function_id)
+            .unwrap();
+
+    chain!([("semantic_diagnostics".into(), semantic_diagnostics)], stages).collect()
+}
+
+#[test]
+fn generic_match_subject() {
+    let db = &mut LoweringDatabaseForTesting::default();
+    let (test_function, semantic_diagnostics) = setup_test_function(
+        db,
+        "fn foo() {}",
+        "foo",
+        indoc! {"
+            fn match_type_parameter<T>(x: T) -> felt {
+                match x { Option::Some(_) => 1, Option::None(_) => 0, }
+            }
+            fn match_generic_enum<T>(x: Option::<T>) -> Option::<T> {
+                match x { Option::Some(v) => Option::Some(v), Option::None(_) => Option::None(()), }
+            }
+        "},
+    )
+    .split();
+    // A subject whose type is a type parameter is rejected by the semantic model, so lowering
+    // fails without reporting additional diagnostics.
+    assert!(semantic_diagnostics.contains("\"T\" is not an enum."));
+    // A generic enum is concrete even before monomorphization, so it is lowered as usual.
+    assert_eq!(db.module_lowering_diagnostics(test_function.module_id).unwrap().format(db), "");
+    let free_function_id = db
+        .module_free_functions_ids(test_function.module_id)
+        .unwrap()
+        .into_iter()
+        .find(|free_function_id| free_function_id.name(db) == "match_generic_enum")
+        .unwrap();
+    assert!(
+        db.priv_function_with_body_lowered_flat(FunctionWithBodyId::Free(free_function_id))
+            .unwrap()
+            .root
+            .is_ok()
+    );
+}
+
+#[test]
+fn core_assert_lowering() {
+    let db = &mut LoweringDatabaseForTesting::default();
+    let assert_function_id = extract_matches!(
+        db.module_item_by_name(core_module(db), "assert".into()).unwrap().unwrap(),
+        ModuleItemId::FreeFunction
+    );
+    let lowered = db
+        .priv_function_with_body_lowered_structured(FunctionWithBodyId::Free(assert_function_id))
+        .unwrap();
+
+    // The false arm of the condition continues, and the true arm of its negation panics with the
+    // error code.
+    assert_eq!(
+        format!("{:?}", lowered.debug(&LoweredFormatter { db, variables: &lowered.variables })),
+        indoc! {"
+            blk0:
+            Inputs: v3: ()
+            Initial refs:
+            Statements:
+              (v4: core::array::Array::<core::felt>) <- core::array::ArrayImpl::<core::felt>::new()
+              (v6: core::array::Array::<core::felt>, v5: ()) <- core::array::ArrayImpl::<core::felt>::append(v4, v1)
+            End:
+              Panic(v6)
+
+            blk1:
+            Inputs: v7: ()
+            Initial refs:
+            Statements:
+            End:
+              Callsite({})
+
+            blk2 (root):
+            Inputs: v0: core::bool, v1: core::felt
+            Initial refs:
+            Statements:
+              (v2: core::bool) <- core::bool_not(v0)
+              () <- match_enum(v2) {
+                bool::False => blk1,
+                bool::True => blk0,
+              }
+              (v8: ()) <- struct_construct()
+            End:
+              Return(v8)
+
+        "}
+    );
+}
+
+#[test]
+fn match_lowering_is_deterministic() {
+    let lower_match = || {
+        let db = &mut LoweringDatabaseForTesting::default();
+        let test_function = setup_test_function(
+            db,
+            indoc! {"
+                fn foo(ref a: felt, b: felt, e: MyEnum) -> felt {
+                    match e {
+                        MyEnum::A(x) => {
+                            a = x + b;
+                            b
+                        },
+                        MyEnum::B(_) => a,
+                        MyEnum::C((x, y)) => {
+                            a = y;
+                            x + a + b
+                        },
+                    }
+                }
+            "},
+            "foo",
+            indoc! {"
+                enum MyEnum {
+                    A: felt,
+                    B: (),
+                    C: (felt, felt),
+                }
+            "},
+        )
+        .unwrap();
+        let lowered =
+            db.concrete_function_with_body_lowered(test_function.concrete_function_id).unwrap();
+        format!("{:?}", lowered.debug(&LoweredFormatter { db, variables: &lowered.variables }))
+    };
+    assert_eq!(lower_match(), lower_match());
+}
+
+#[test]
+fn flat_block_statement_replacement() {
+    let db = &mut LoweringDatabaseForTesting::default();
+    let test_function =
+        setup_test_function(db, "fn foo() -> felt { bar() + 1 }", "foo", "fn bar() -> felt { 5 }")
+            .unwrap();
+    let mut lowered =
+        (*db.priv_function_with_body_lowered_flat(test_function.function_id).unwrap()).clone();
+    let root = lowered.root.unwrap();
+    let call = extract_matches!(&lowered.blocks[root].statements[0], Statement::Call);
+    let literal = Statement::Literal(StatementLiteral { value: 5.into(), output: call.outputs[0] });
+
+    let replaced = lowered.blocks[root].replace_statement(0, literal.clone());
+    assert!(matches!(replaced, Statement::Call(_)));
+    assert_eq!(lowered.blocks[root].statements[0], literal);
+
+    let removed = lowered.blocks[root].remove_statement(1);
+    assert!(
+        matches!(removed, Statement::Literal(StatementLiteral { value, .. }) if value == 1.into())
+    );
+    assert_eq!(lowered.blocks[root].statements.len(), 2);
+}
+
+#[test]
+fn match_enum_input_is_lowered_subject() {
+    let db = &mut LoweringDatabaseForTesting::default();
+    let test_function = setup_test_function(
+        db,
+        indoc! {"
+            fn foo() -> felt {
+                match bar() {
+                    Option::Some(x) => x,
+                    Option::None(_) => 0,
+                }
+            }
+        "},
+        "foo",
+        "fn bar() -> Option::<felt> { Option::Some(5) }",
+    )
+    .unwrap();
+    let lowered = db.priv_function_with_body_lowered_flat(test_function.function_id).unwrap();
+    let root_statements = &lowered.blocks[lowered.root.unwrap()].statements;
+    let call = extract_matches!(&root_statements[0], Statement::Call);
+    let match_enum = extract_matches!(&root_statements[1], Statement::MatchEnum);
+    assert_eq!(call.outputs, vec![match_enum.input]);
+}
+
+#[test]
+fn lowered_formatting() {
+    let db = &mut LoweringDatabaseForTesting::default();
+    let test_function =
+        setup_test_function(db, "fn foo(a: felt) -> felt { a + 1 }", "foo", "").unwrap();
+    let lowered = db.priv_function_with_body_lowered_flat(test_function.function_id).unwrap();
+    let lowered_formatter = LoweredFormatter { db, variables: &lowered.variables };
+    assert_eq!(
+        format!("{:?}", lowered.debug(&lowered_formatter)),
+        indoc! {"
+            blk0 (root):
+            Inputs: v0: core::felt
+            Statements:
+              (v1: core::felt) <- 1u
+              (v2: core::felt) <- core::FeltAdd::add(v0, v1)
+            End:
+              Return(v2)
+
+        "}
+    );
+}
+
+#[test]
+fn validate_definitions() {
+    let db = &mut LoweringDatabaseForTesting::default();
+    let test_function = setup_test_function(
+        db,
+        indoc! {"
+            fn foo(a: felt) -> felt {
+                let b = match bar() {
+                    Option::Some(x) => x + a,
+                    Option::None(_) => a,
+                };
+                b + 1
+            }
+        "},
+        "foo",
+        "fn bar() -> Option::<felt> { Option::Some(5) }",
+    )
+    .unwrap();
+    let lowered =
+        db.concrete_function_with_body_lowered(test_function.concrete_function_id).unwrap();
+    assert_eq!(lowered.validate_definitions(), Ok(vec![]));
+
+    // Removing the call to `bar` leaves its output undefined when matched on.
+    let mut broken = (*lowered).clone();
+    let root = broken.root.unwrap();
+    broken.blocks[root].remove_statement(0);
+    assert_eq!(
+        broken.validate_definitions(),
+        Ok(vec!["v1 is used in blk2 before it is defined.".into()])
+    );
+
+    // Repeating the call to `bar` defines its output twice.
+    let mut broken = (*lowered).clone();
+    let call = broken.blocks[root].statements[0].clone();
+    broken.blocks[root].statements.insert(0, call);
+    assert_eq!(broken.validate_definitions(), Ok(vec!["v1 is defined more than once.".into()]));
+}
+
+#[test]
+fn edges_into_root() {
+    let db = &mut LoweringDatabaseForTesting::default();
+    let test_function = setup_test_function(
+        db,
+        indoc! {"
+            fn foo(a: felt) -> felt {
+                match bar() {
+                    Option::Some(x) => x + a,
+                    Option::None(_) => a,
+                }
+            }
+        "},
+        "foo",
+        "fn bar() -> Option::<felt> { Option::Some(5) }",
+    )
+    .unwrap();
+    let lowered =
+        db.concrete_function_with_body_lowered(test_function.concrete_function_id).unwrap();
+    let root = lowered.root.unwrap();
+    assert_eq!(lowered.edges_into_root(), Ok(vec![]));
+
+    // Making a match arm jump back to the root is reported instead of panicking.
+    let mut broken = (*lowered).clone();
+    let arm = broken.blocks[root].successors()[0];
+    broken.blocks[arm].end = FlatBlockEnd::Goto(root, VarRemapping::default());
+    assert_eq!(broken.predecessors().unwrap()[root], vec![arm]);
+    assert_eq!(broken.edges_into_root(), Ok(vec![(arm, root)]));
+}
+
+#[test]
+fn blocks_iteration_order() {
+    let mut blocks = Blocks::<&str>::new();
+    let ids = ["a", "b", "c"].map(|block| blocks.alloc(block));
+    assert_eq!(
+        blocks.iter().collect::<Vec<_>>(),
+        vec![(ids[0], &"a"), (ids[1], &"b"), (ids[2], &"c")]
+    );
+
+    // Blocks referenced out of order, and more than once, are yielded once each in id order.
+    assert_eq!(
+        blocks.iter_in_id_order([ids[2], ids[0], ids[2], ids[1]]).collect::<Vec<_>>(),
+        vec![(BlockId(0), &"a"), (BlockId(1), &"b"), (BlockId(2), &"c")]
+    );
+}
+
+#[test]
+fn function_without_body() {
+    // A function written without a body is reported before lowering.
+    let db = &mut LoweringDatabaseForTesting::default();
+    let (test_function, semantic_diagnostics) =
+        setup_test_function(db, "fn foo() -> felt;", "foo", "").split();
+    assert!(semantic_diagnostics.contains("Skipped tokens. Expected: '{'."));
+    assert!(db.function_body(test_function.function_id).is_ok());
+
+    // An extern function legitimately has no body, and is not lowered.
+    let (test_function, semantic_diagnostics) = setup_test_function(
+        db,
+        "fn foo() -> felt { bar() }",
+        "foo",
+        "extern fn bar() -> felt nopanic;",
+    )
+    .split();
+    assert_eq!(semantic_diagnostics, "");
+    assert_eq!(db.module_lowering_diagnostics(test_function.module_id).unwrap().format(db), "");
+    assert!(
+        db.module_free_functions_ids(test_function.module_id)
+            .unwrap()
+            .iter()
+            .all(|free_function_id| free_function_id.name(db) != "bar")
+    );
+}
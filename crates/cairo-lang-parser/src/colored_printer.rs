@@ -99,6 +99,7 @@ fn set_color(text: SmolStr, kind: SyntaxKind) -> ColoredString {
         | SyntaxKind::TokenOrOr
         | SyntaxKind::TokenXor
         | SyntaxKind::TokenNot
+        | SyntaxKind::TokenAt
         | SyntaxKind::TokenQuestionMark
         | SyntaxKind::TokenUnderscore
         | SyntaxKind::TokenHash => text.truecolor(255, 180, 255), // Pink
@@ -235,6 +235,7 @@ impl<'a> Lexer<'a> {
                 '%' => self.take_token_of_kind(TokenKind::Mod),
                 '+' => self.take_token_of_kind(TokenKind::Plus),
                 '#' => self.take_token_of_kind(TokenKind::Hash),
+                '@' => self.take_token_of_kind(TokenKind::At),
                 '-' => self.pick_kind('>', TokenKind::Arrow, TokenKind::Minus),
                 '<' => self.pick_kind('=', TokenKind::LE, TokenKind::LT),
                 '>' => self.pick_kind('=', TokenKind::GE, TokenKind::GT),
@@ -371,6 +372,7 @@ enum TokenKind {
     LParen,
     RParen,
     Arrow,
+    At,
     MatchArrow,
 
     // Meta.
@@ -439,6 +441,7 @@ fn token_kind_to_terminal_syntax_kind(kind: TokenKind) -> SyntaxKind {
         TokenKind::Ref => SyntaxKind::TerminalRef,
         TokenKind::Mut => SyntaxKind::TerminalMut,
         TokenKind::Arrow => SyntaxKind::TerminalArrow,
+        TokenKind::At => SyntaxKind::TerminalAt,
         TokenKind::MatchArrow => SyntaxKind::TerminalMatchArrow,
         TokenKind::BadCharacters => SyntaxKind::TerminalBadCharacters,
         TokenKind::EndOfFile => SyntaxKind::TerminalEndOfFile,
@@ -78,6 +78,7 @@ fn terminal_kind_to_text(kind: SyntaxKind) -> Vec<&'static str> {
         SyntaxKind::TerminalLParen => vec!["("],
         SyntaxKind::TerminalRParen => vec![")"],
         SyntaxKind::TerminalArrow => vec!["->"],
+        SyntaxKind::TerminalAt => vec!["@"],
         SyntaxKind::TerminalMatchArrow => vec!["=>"],
         SyntaxKind::TerminalEndOfFile => vec![],
         _ => {
@@ -144,6 +145,7 @@ fn terminal_kinds() -> Vec<SyntaxKind> {
         SyntaxKind::TerminalLParen,
         SyntaxKind::TerminalRParen,
         SyntaxKind::TerminalArrow,
+        SyntaxKind::TerminalAt,
         SyntaxKind::TerminalMatchArrow,
         SyntaxKind::TerminalEndOfFile,
     ]
@@ -315,7 +317,7 @@ fn test_cases() {
     let db_val = SimpleParserDatabase::default();
     let db = &db_val;
     let res: Vec<LexerTerminal> =
-        Lexer::from_text(db, test_source(), "let x: &T = @ 6; //  5+ 3;").collect();
+        Lexer::from_text(db, test_source(), "let x: &T = $ 6; //  5+ 3;").collect();
     assert_eq!(
         res,
         vec![
@@ -356,7 +358,7 @@ fn test_cases() {
                 trailing_trivia: vec![TokenWhitespace::new_green(db, " ".into()).into()]
             },
             LexerTerminal {
-                text: "@".into(),
+                text: "$".into(),
                 kind: SyntaxKind::TerminalBadCharacters,
                 leading_trivia: vec![],
                 trailing_trivia: vec![TokenWhitespace::new_green(db, " ".into()).into()]
@@ -391,7 +393,7 @@ fn test_bad_character() {
     let db_val = SimpleParserDatabase::default();
     let db = &db_val;
 
-    let text = "@";
+    let text = "$";
     let mut lexer = Lexer::from_text(db, test_source(), text);
     let terminal = lexer.next().unwrap();
     let token_text = terminal.text;
@@ -1,7 +1,8 @@
 use cairo_lang_syntax::node::kind::SyntaxKind;
 
 pub fn get_unary_operator_precedence(kind: SyntaxKind) -> Option<usize> {
-    if [SyntaxKind::TerminalNot, SyntaxKind::TerminalMinus].contains(&kind) {
+    if [SyntaxKind::TerminalAt, SyntaxKind::TerminalNot, SyntaxKind::TerminalMinus].contains(&kind)
+    {
         get_binary_operator_precedence(kind)
     } else {
         None
@@ -13,7 +14,7 @@ pub fn get_binary_operator_precedence(kind: SyntaxKind) -> Option<usize> {
         SyntaxKind::TerminalDot => Some(0),
 
         // TODO(yuval): support unary-only/non-binary operators. "not" can't be binary.
-        SyntaxKind::TerminalNot => Some(1),
+        SyntaxKind::TerminalAt | SyntaxKind::TerminalNot => Some(1),
         SyntaxKind::TerminalMul | SyntaxKind::TerminalDiv | SyntaxKind::TerminalMod => Some(2),
         SyntaxKind::TerminalPlus | SyntaxKind::TerminalMinus => Some(3),
         SyntaxKind::TerminalEqEq
@@ -25,7 +26,9 @@ pub fn get_binary_operator_precedence(kind: SyntaxKind) -> Option<usize> {
         SyntaxKind::TerminalAnd => Some(5),
         SyntaxKind::TerminalOr => Some(6),
         SyntaxKind::TerminalXor => Some(7),
-        SyntaxKind::TerminalEq => Some(8),
+        SyntaxKind::TerminalAndAnd => Some(8),
+        SyntaxKind::TerminalOrOr => Some(9),
+        SyntaxKind::TerminalEq => Some(10),
 
         // TODO(yuval): add more operators.
         _ => None,
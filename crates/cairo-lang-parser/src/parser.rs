@@ -55,7 +55,7 @@ pub struct Parser<'a> {
 // never a missing kind.
 // Should only be called after checking the current token.
 
-const MAX_PRECEDENCE: usize = 10;
+const MAX_PRECEDENCE: usize = 12;
 impl<'a> Parser<'a> {
     /// Parses a file.
     pub fn parse_file(
@@ -541,7 +541,7 @@ impl<'a> Parser<'a> {
     /// Assumes the current token is an operator (binary or unary).
     /// Returns a GreenId of the operator or None if the operator is a unary-only operator.
     fn try_parse_binary_operator(&mut self) -> Option<BinaryOperatorGreen> {
-        if self.peek().kind == SyntaxKind::TerminalNot {
+        if [SyntaxKind::TerminalAt, SyntaxKind::TerminalNot].contains(&self.peek().kind) {
             None
         } else {
             Some(match self.peek().kind {
@@ -561,6 +561,8 @@ impl<'a> Parser<'a> {
                 SyntaxKind::TerminalAnd => self.take::<TerminalAnd>().into(),
                 SyntaxKind::TerminalOr => self.take::<TerminalOr>().into(),
                 SyntaxKind::TerminalXor => self.take::<TerminalXor>().into(),
+                SyntaxKind::TerminalAndAnd => self.take::<TerminalAndAnd>().into(),
+                SyntaxKind::TerminalOrOr => self.take::<TerminalOrOr>().into(),
                 _ => unreachable!(),
             })
         }
@@ -570,6 +572,7 @@ impl<'a> Parser<'a> {
         match self.peek().kind {
             SyntaxKind::TerminalNot => self.take::<TerminalNot>().into(),
             SyntaxKind::TerminalMinus => self.take::<TerminalMinus>().into(),
+            SyntaxKind::TerminalAt => self.take::<TerminalAt>().into(),
             _ => unreachable!(),
         }
     }
@@ -316,7 +316,9 @@ cairo_lang_test_utils::test_file_test!(
         let_statement: "let_statement",
         if_else: "if_else",
         literal: "literal",
+        logical_operators: "logical_operators",
         module: "module",
+        snapshot: "snapshot",
     },
     test_partial_parser_tree
 );
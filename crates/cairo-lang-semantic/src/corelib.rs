@@ -33,6 +33,21 @@ pub fn core_nonzero_ty(db: &dyn SemanticGroup, inner_type: TypeId) -> TypeId {
     get_core_ty_by_name(db, "NonZero".into(), vec![GenericArgumentId::Type(inner_type)])
 }
 
+pub fn core_snapshot_ty(db: &dyn SemanticGroup, inner_type: TypeId) -> TypeId {
+    get_core_ty_by_name(db, "Snapshot".into(), vec![GenericArgumentId::Type(inner_type)])
+}
+
+/// Returns the type wrapped by a `Snapshot` type, or None if the type is not a snapshot.
+pub fn unwrap_snapshot_ty(db: &dyn SemanticGroup, ty: TypeId) -> Option<TypeId> {
+    let TypeLongId::Concrete(concrete_type_id) = db.lookup_intern_type(ty) else {
+        return None;
+    };
+    let [GenericArgumentId::Type(inner_type)] = concrete_type_id.generic_args(db)[..] else {
+        return None;
+    };
+    if core_snapshot_ty(db, inner_type) == ty { Some(inner_type) } else { None }
+}
+
 pub fn try_get_core_ty_by_name(
     db: &dyn SemanticGroup,
     name: SmolStr,
@@ -270,6 +285,8 @@ pub fn core_unary_operator(
 
         UnaryOperator::Not(_) if ty == bool_ty => "bool_not",
         UnaryOperator::Not(_) => return unsupported_operator("!"),
+
+        UnaryOperator::At(_) => return unsupported_operator("@"),
     };
     Ok(get_core_function_id(db, function_name.into(), vec![]))
 }
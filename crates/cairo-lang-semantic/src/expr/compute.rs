@@ -4,7 +4,7 @@
 
 use std::collections::HashMap;
 
-use ast::{BinaryOperator, PathSegment};
+use ast::{BinaryOperator, PathSegment, UnaryOperator};
 use cairo_lang_defs::ids::{FunctionSignatureId, LocalVarLongId, MemberId, TraitId};
 use cairo_lang_diagnostics::{skip_diagnostic, Maybe, ToMaybe, ToOption};
 use cairo_lang_syntax::node::ast::{BlockOrIf, PatternStructParam};
@@ -28,9 +28,9 @@ use super::pattern::{
     Pattern, PatternEnumVariant, PatternLiteral, PatternOtherwise, PatternTuple, PatternVariable,
 };
 use crate::corelib::{
-    core_binary_operator, core_felt_ty, core_unary_operator, false_literal_expr, never_ty,
-    true_literal_expr, try_get_const_libfunc_name_by_type, try_get_core_ty_by_name, unit_ty,
-    unwrap_error_propagation_type,
+    core_binary_operator, core_bool_ty, core_felt_ty, core_snapshot_ty, core_unary_operator,
+    false_literal_expr, never_ty, true_literal_expr, try_get_const_libfunc_name_by_type,
+    try_get_core_ty_by_name, unit_ty, unwrap_error_propagation_type, unwrap_snapshot_ty,
 };
 use crate::db::SemanticGroup;
 use crate::diagnostic::SemanticDiagnosticKind::*;
@@ -228,6 +228,13 @@ fn compute_expr_unary_semantic(
     let expr = compute_expr_semantic(ctx, &syntax.expr(syntax_db));
 
     let expr_ty = ctx.reduce_ty(expr.ty());
+    if matches!(unary_op, UnaryOperator::At(_)) {
+        return Ok(Expr::Snapshot(ExprSnapshot {
+            inner: ctx.exprs.alloc(expr),
+            ty: core_snapshot_ty(ctx.db, expr_ty),
+            stable_ptr: syntax.stable_ptr().into(),
+        }));
+    }
     let function = match core_unary_operator(ctx.db, &unary_op, expr_ty) {
         Err(err_kind) => {
             return Err(ctx.diagnostics.report(&unary_op, err_kind));
@@ -285,6 +292,30 @@ fn compute_expr_binary_semantic(
             _ => Err(ctx.diagnostics.report(lhs_syntax, InvalidLhsForAssignment)),
         };
     }
+    let logical_op = match binary_op {
+        BinaryOperator::AndAnd(_) => Some(LogicalOperator::AndAnd),
+        BinaryOperator::OrOr(_) => Some(LogicalOperator::OrOr),
+        _ => None,
+    };
+    if let Some(op) = logical_op {
+        let bool_ty = core_bool_ty(db);
+        for (expr, expr_syntax) in [(&lexpr, lhs_syntax), (&rexpr, &rhs_syntax)] {
+            let actual_ty = ctx.reduce_ty(expr.ty());
+            actual_ty.check_not_missing(db)?;
+            if ctx.inference.conform_ty(actual_ty, bool_ty).is_err() {
+                return Err(ctx
+                    .diagnostics
+                    .report(expr_syntax, WrongType { expected_ty: bool_ty, actual_ty }));
+            }
+        }
+        return Ok(Expr::LogicalOperator(ExprLogicalOperator {
+            lhs: ctx.exprs.alloc(lexpr),
+            op,
+            rhs: ctx.exprs.alloc(rexpr),
+            ty: bool_ty,
+            stable_ptr,
+        }));
+    }
     ctx.reduce_ty(lexpr.ty()).check_not_missing(db)?;
     ctx.reduce_ty(rexpr.ty()).check_not_missing(db)?;
     let function = match core_binary_operator(
@@ -546,6 +577,8 @@ pub fn compute_root_expr(
                 ctx.inference.reduce_concrete_variant(&mut expr.ok_variant);
                 ctx.inference.reduce_concrete_variant(&mut expr.func_err_variant);
             }
+            Expr::LogicalOperator(_) => {}
+            Expr::Snapshot(expr) => expr.ty = ctx.inference.reduce_ty(expr.ty),
             Expr::Missing(_) => {}
         };
     }
@@ -1355,7 +1388,9 @@ fn member_access_expr(
 
     // Find MemberId.
     let member_name = expr_as_identifier(ctx, &rhs_syntax, syntax_db)?;
-    match ctx.db.lookup_intern_type(lexpr.ty()) {
+    // Members of a snapshot of a struct are accessed as snapshots.
+    let snapshot_inner_ty = unwrap_snapshot_ty(ctx.db, lexpr.ty());
+    match ctx.db.lookup_intern_type(snapshot_inner_ty.unwrap_or_else(|| lexpr.ty())) {
         TypeLongId::Concrete(concrete) => match concrete {
             ConcreteTypeId::Struct(concrete_struct_id) => {
                 // TODO(lior): Add a diagnostic test when accessing a member of a missing type.
@@ -1374,7 +1409,10 @@ fn member_access_expr(
                     expr: lexpr_id,
                     struct_id: concrete_struct_id.struct_id(ctx.db),
                     member: member.id,
-                    ty: member.ty,
+                    ty: match snapshot_inner_ty {
+                        Some(_) => core_snapshot_ty(ctx.db, member.ty),
+                        None => member.ty,
+                    },
                     stable_ptr,
                 }))
             }
@@ -94,6 +94,8 @@ pub enum Expr {
     EnumVariantCtor(ExprEnumVariantCtor),
     PropagateError(ExprPropagateError),
     Constant(ExprConstant),
+    LogicalOperator(ExprLogicalOperator),
+    Snapshot(ExprSnapshot),
     Missing(ExprMissing),
 }
 impl Expr {
@@ -112,6 +114,8 @@ impl Expr {
             Expr::EnumVariantCtor(expr) => expr.ty,
             Expr::PropagateError(expr) => expr.ok_variant.ty,
             Expr::Constant(expr) => expr.ty,
+            Expr::LogicalOperator(expr) => expr.ty,
+            Expr::Snapshot(expr) => expr.ty,
             Expr::Missing(expr) => expr.ty,
         }
     }
@@ -130,6 +134,8 @@ impl Expr {
             Expr::EnumVariantCtor(expr) => expr.stable_ptr,
             Expr::PropagateError(expr) => expr.stable_ptr,
             Expr::Constant(expr) => expr.stable_ptr,
+            Expr::LogicalOperator(expr) => expr.stable_ptr,
+            Expr::Snapshot(expr) => expr.stable_ptr,
             Expr::Missing(expr) => expr.stable_ptr,
         }
     }
@@ -277,6 +283,38 @@ pub struct ExprConstant {
     pub stable_ptr: ast::ExprPtr,
 }
 
+/// A short-circuiting logical operator.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum LogicalOperator {
+    /// `a && b`: `b` is evaluated only if `a` is true.
+    AndAnd,
+    /// `a || b`: `b` is evaluated only if `a` is false.
+    OrOr,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb)]
+#[debug_db(ExprFormatter<'a>)]
+pub struct ExprLogicalOperator {
+    pub lhs: ExprId,
+    pub op: LogicalOperator,
+    pub rhs: ExprId,
+    // ExprLogicalOperator is always of bool type.
+    pub ty: semantic::TypeId,
+    #[hide_field_debug_with_db]
+    pub stable_ptr: ast::ExprPtr,
+}
+
+/// A snapshot of a value (`@expr`).
+#[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb)]
+#[debug_db(ExprFormatter<'a>)]
+pub struct ExprSnapshot {
+    pub inner: ExprId,
+    // ExprSnapshot is always of type `Snapshot<T>`, where T is the type of `inner`.
+    pub ty: semantic::TypeId,
+    #[hide_field_debug_with_db]
+    pub stable_ptr: ast::ExprPtr,
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb)]
 #[debug_db(ExprFormatter<'a>)]
 pub struct ExprMissing {
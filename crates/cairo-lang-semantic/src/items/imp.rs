@@ -496,11 +496,16 @@ pub fn find_impls_at_module(
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct ImplLookupContext {
     pub module_id: ModuleId,
+    /// Additional modules to look for impls in. They are searched in order, right after
+    /// `module_id`, so impls from earlier modules come first in the lookup results.
     pub extra_modules: Vec<ModuleId>,
     pub generic_params: Vec<GenericParamId>,
 }
 
 /// Finds all the implementations of a concrete trait, in a specific lookup context.
+/// The order is deterministic: `module_id`, then `extra_modules` in order, then the core module,
+/// then the submodules and the used modules of `module_id` in declaration order.
+/// Note that an ambiguity is not resolved by this order - callers report it as a diagnostic.
 pub fn find_impls_at_context(
     db: &dyn SemanticGroup,
     inference: &Inference<'_>,
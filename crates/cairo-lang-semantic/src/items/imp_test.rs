@@ -1,10 +1,14 @@
 use cairo_lang_debug::DebugWithDb;
-use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::ids::{ModuleId, ModuleItemId};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::stable_ptr::SyntaxStablePtr;
 use cairo_lang_utils::extract_matches;
 use pretty_assertions::assert_eq;
 use test_log::test;
 
+use super::{find_impls_at_context, ImplLookupContext};
 use crate::db::SemanticGroup;
+use crate::expr::inference::Inference;
 use crate::test_utils::{setup_test_module, SemanticDatabaseForTesting};
 
 // TODO(ilya): enable test once impls are enabled.
@@ -50,3 +54,70 @@ fn test_impl() {
 
     assert_eq!(format!("{:?}", db.impl_trait(impl_id).unwrap()), "ConcreteTraitId(0)");
 }
+
+#[test]
+fn test_find_impls_at_context_order() {
+    let mut db_val = SemanticDatabaseForTesting::default();
+    let db = &mut db_val;
+    let (test_module, diagnostics) = setup_test_module(
+        db,
+        indoc::indoc! {"
+            trait MyTrait {
+                fn foo(a: felt) -> felt;
+            }
+            mod a {
+                impl AImpl of super::MyTrait {
+                    fn foo(a: felt) -> felt {
+                        a
+                    }
+                }
+            }
+            mod b {
+                impl BImpl of super::MyTrait {
+                    fn foo(a: felt) -> felt {
+                        a
+                    }
+                }
+            }
+            mod c {}
+        "},
+    )
+    .split();
+    assert_eq!(diagnostics, "");
+
+    let submodule = |name: &str| {
+        ModuleId::Submodule(extract_matches!(
+            db.module_item_by_name(test_module.module_id, name.into()).unwrap().unwrap(),
+            ModuleItemId::Submodule
+        ))
+    };
+    let impl_in = |module_id: ModuleId, name: &str| {
+        extract_matches!(
+            db.module_item_by_name(module_id, name.into()).unwrap().unwrap(),
+            ModuleItemId::Impl
+        )
+    };
+    let a_impl = impl_in(submodule("a"), "AImpl");
+    let b_impl = impl_in(submodule("b"), "BImpl");
+    let concrete_trait_id = db.impl_trait(a_impl).unwrap();
+    let stable_ptr = db.intern_stable_ptr(SyntaxStablePtr::Root);
+
+    // The lookup is done from an unrelated module, so the impls are found only through the extra
+    // modules, in their order.
+    for (extra_modules, expected) in [
+        (vec![submodule("a"), submodule("b")], [a_impl, b_impl]),
+        (vec![submodule("b"), submodule("a")], [b_impl, a_impl]),
+    ] {
+        let lookup_context =
+            ImplLookupContext { module_id: submodule("c"), extra_modules, generic_params: vec![] };
+        let impls = find_impls_at_context(
+            db,
+            &Inference::new(db),
+            &lookup_context,
+            concrete_trait_id,
+            stable_ptr,
+        )
+        .unwrap();
+        assert_eq!(impls.into_iter().collect::<Vec<_>>(), expected);
+    }
+}
@@ -1,7 +1,24 @@
+use lazy_static::lazy_static;
 use num_bigint::BigInt;
-use num_traits::Num;
+use num_traits::{Num, One, Signed};
 use smol_str::SmolStr;
 
+#[cfg(test)]
+#[path = "literals_test.rs"]
+mod test;
+
+lazy_static! {
+    /// The prime of the field of felt values, 2^251 + 17 * 2^192 + 1.
+    pub static ref FELT_PRIME: BigInt =
+        (BigInt::one() << 251) + (BigInt::from(17) << 192) + BigInt::one();
+}
+
+/// Returns the representative of a felt value in the field, in `[0, prime)`.
+pub fn reduce_felt_value(value: &BigInt) -> BigInt {
+    let reduced = value % &*FELT_PRIME;
+    if reduced.is_negative() { reduced + &*FELT_PRIME } else { reduced }
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct LiteralLongId {
     pub value: BigInt,
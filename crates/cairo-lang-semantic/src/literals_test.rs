@@ -0,0 +1,24 @@
+use num_bigint::BigInt;
+use test_case::test_case;
+
+use super::{reduce_felt_value, FELT_PRIME};
+
+/// Tests the reduction of `offset + prime_factor * prime`, expecting `expected_offset` modulo
+/// prime, in `[0, prime)`.
+#[test_case(0, 0, 0; "zero")]
+#[test_case(5, 0, 5; "five")]
+#[test_case(0, 1, 0; "prime")]
+#[test_case(-1, 1, -1; "prime minus one")]
+#[test_case(1, 1, 1; "prime plus one")]
+#[test_case(-1, 0, -1; "minus one")]
+#[test_case(0, -1, 0; "minus prime")]
+#[test_case(5, -2, 5; "five minus two primes")]
+fn test_reduce_felt_value(offset: i64, prime_factor: i64, expected_offset: i64) {
+    let value = BigInt::from(offset) + BigInt::from(prime_factor) * &*FELT_PRIME;
+    let expected = if expected_offset < 0 {
+        &*FELT_PRIME + expected_offset
+    } else {
+        BigInt::from(expected_offset)
+    };
+    assert_eq!(reduce_felt_value(&value), expected);
+}
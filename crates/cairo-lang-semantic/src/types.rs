@@ -20,6 +20,10 @@ use crate::items::imp::{find_impls_at_context, ImplLookupContext};
 use crate::resolve_path::{ResolvedConcreteItem, Resolver};
 use crate::{semantic, ConcreteImplId, ConcreteVariant, FunctionId, GenericArgumentId};
 
+#[cfg(test)]
+#[path = "types_test.rs"]
+mod test;
+
 /// A substitution of generic arguments in generic parameters. Used for concretization.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct GenericSubstitution(pub OrderedHashMap<GenericParamId, GenericArgumentId>);
@@ -291,6 +295,8 @@ pub fn maybe_resolve_type(
                 .collect();
             db.intern_type(TypeLongId::Tuple(sub_tys))
         }
+        // TODO: Resolve fixed size array types (`[T; N]`) once they are parsed, folding const
+        //   arithmetic over literals in `N` (e.g. `2 + 1`) and reporting non-const sizes.
         _ => {
             return Err(diagnostics.report(ty_syntax, UnknownType));
         }
@@ -415,8 +421,11 @@ pub fn type_info(
     ty: TypeId,
 ) -> Maybe<TypeInfo> {
     // TODO(spapini): Validate Copy and Drop for structs and enums.
-    let inference = Inference::disabled(db);
-    // Dummy stable pointer for type inference variables, since inference is disabled.
+    // Inference is used only for matching generic impls (e.g. `impl SnapshotCopy<T> of
+    // Copy::<Snapshot::<T>>`), and its results are not kept.
+    let inference = Inference::new(db);
+    // Dummy stable pointer for type inference variables, as the inferred variables are never
+    // reported.
     let stable_ptr = db.intern_stable_ptr(SyntaxStablePtr::Root);
     Ok(match db.lookup_intern_type(ty) {
         TypeLongId::Concrete(concrete_type_id) => {
@@ -0,0 +1,34 @@
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_utils::extract_matches;
+use pretty_assertions::assert_eq;
+use test_log::test;
+
+use super::{substitute_ty, GenericSubstitution};
+use crate::corelib::core_felt_ty;
+use crate::db::SemanticGroup;
+use crate::test_utils::{setup_test_module, SemanticDatabaseForTesting};
+use crate::GenericArgumentId;
+
+#[test]
+fn test_substitute_nested_generic_ty() {
+    let mut db_val = SemanticDatabaseForTesting::default();
+    let db = &mut db_val;
+    let test_module =
+        setup_test_module(db, "fn foo<T>(a: Option::<Array::<T>>) {}").unwrap().module_id;
+    let free_function_id = extract_matches!(
+        db.module_item_by_name(test_module, "foo".into()).unwrap().unwrap(),
+        ModuleItemId::FreeFunction
+    );
+    let generic_param = db.free_function_generic_params(free_function_id).unwrap()[0];
+    let param_ty = db.free_function_signature(free_function_id).unwrap().params[0].ty;
+
+    let substitution = GenericSubstitution(
+        [(generic_param, GenericArgumentId::Type(core_felt_ty(db)))].into_iter().collect(),
+    );
+    assert_eq!(
+        substitute_ty(db, &substitution, param_ty).format(db),
+        "core::option::Option::<core::array::Array::<core::felt>>"
+    );
+    // Types that do not contain the substituted parameter are not changed.
+    assert_eq!(substitute_ty(db, &substitution, core_felt_ty(db)), core_felt_ty(db));
+}
@@ -75,6 +75,10 @@ pub fn core_libfunc_ap_change<InfoProvider: InvocationApChangeInfoProvider>(
                 ]
             }
             BuiltinCostConcreteLibfunc::GetBuiltinCosts(_) => vec![ApChange::Known(3)],
+            BuiltinCostConcreteLibfunc::CouponBuy(_) => {
+                vec![ApChange::Known(2), ApChange::Known(2)]
+            }
+            BuiltinCostConcreteLibfunc::CouponRefund(_) => vec![ApChange::Known(0)],
         },
         CoreConcreteLibfunc::Ec(libfunc) => match libfunc {
             EcConcreteLibfunc::IsZero(_) => vec![ApChange::Known(0), ApChange::Known(0)],
@@ -88,7 +92,9 @@ pub fn core_libfunc_ap_change<InfoProvider: InvocationApChangeInfoProvider>(
             EcConcreteLibfunc::UnwrapPoint(_) => vec![ApChange::Known(0)],
             EcConcreteLibfunc::Zero(_) => vec![ApChange::Known(0)],
         },
-        CoreConcreteLibfunc::Drop(_) | CoreConcreteLibfunc::Dup(_) => vec![ApChange::Known(0)],
+        CoreConcreteLibfunc::Drop(_)
+        | CoreConcreteLibfunc::Dup(_)
+        | CoreConcreteLibfunc::SnapshotTake(_) => vec![ApChange::Known(0)],
         CoreConcreteLibfunc::Felt(libfunc) => match libfunc {
             FeltConcrete::BinaryOperation(_) | FeltConcrete::Const(_) => vec![ApChange::Known(0)],
             FeltConcrete::IsZero(_) => vec![ApChange::Known(0), ApChange::Known(0)],
@@ -137,6 +143,7 @@ pub fn core_libfunc_ap_change<InfoProvider: InvocationApChangeInfoProvider>(
                 }
             },
             Uint128Concrete::Divmod(_) => vec![ApChange::Known(7)],
+            Uint128Concrete::WrappingAdd(_) => vec![ApChange::Known(3)],
             Uint128Concrete::WideMul(_) => vec![ApChange::Known(17)],
             Uint128Concrete::LessThan(_) => vec![ApChange::Known(2), ApChange::Known(3)],
             Uint128Concrete::Equal(_) => vec![ApChange::Known(1), ApChange::Known(1)],
@@ -170,7 +177,9 @@ pub fn core_libfunc_ap_change<InfoProvider: InvocationApChangeInfoProvider>(
             }
         },
         CoreConcreteLibfunc::Struct(libfunc) => match libfunc {
-            StructConcreteLibfunc::Construct(_) | StructConcreteLibfunc::Deconstruct(_) => {
+            StructConcreteLibfunc::Construct(_)
+            | StructConcreteLibfunc::Deconstruct(_)
+            | StructConcreteLibfunc::SnapshotDeconstruct(_) => {
                 vec![ApChange::Known(0)]
             }
         },
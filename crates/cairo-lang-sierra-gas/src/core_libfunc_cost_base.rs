@@ -6,7 +6,7 @@ use cairo_lang_sierra::extensions::builtin_cost::{
 };
 use cairo_lang_sierra::extensions::core::CoreConcreteLibfunc::{
     self, ApTracking, Array, Bitwise, Bool, Box, BranchAlign, BuiltinCost, DictFeltTo, Drop, Dup,
-    Ec, Enum, Felt, FunctionCall, Gas, Mem, Pedersen, Struct, Uint128, Uint64, Uint8,
+    Ec, Enum, Felt, FunctionCall, Gas, Mem, Pedersen, SnapshotTake, Struct, Uint128, Uint64, Uint8,
     UnconditionalJump, UnwrapNonZero,
 };
 use cairo_lang_sierra::extensions::dict_felt_to::DictFeltToConcreteLibfunc;
@@ -225,7 +225,7 @@ pub fn core_libfunc_postcost<Ops: CostOperations, InfoProvider: InvocationCostIn
         Uint8(libfunc) => u8_libfunc_cost(ops, libfunc),
         Uint64(libfunc) => u64_libfunc_cost(ops, libfunc),
         Felt(libfunc) => felt_libfunc_cost(ops, libfunc),
-        Drop(_) | Dup(_) | ApTracking(_) | UnwrapNonZero(_) | Mem(Rename(_)) => {
+        Drop(_) | Dup(_) | SnapshotTake(_) | ApTracking(_) | UnwrapNonZero(_) | Mem(Rename(_)) => {
             vec![ops.steps(0)]
         }
         Box(libfunc) => match libfunc {
@@ -252,7 +252,11 @@ pub fn core_libfunc_postcost<Ops: CostOperations, InfoProvider: InvocationCostIn
         Enum(EnumConcreteLibfunc::Match(sig)) => {
             vec![ops.steps(1); sig.signature.branch_signatures.len()]
         }
-        Struct(StructConcreteLibfunc::Construct(_) | StructConcreteLibfunc::Deconstruct(_)) => {
+        Struct(
+            StructConcreteLibfunc::Construct(_)
+            | StructConcreteLibfunc::Deconstruct(_)
+            | StructConcreteLibfunc::SnapshotDeconstruct(_),
+        ) => {
             vec![ops.steps(0)]
         }
         DictFeltTo(DictFeltToConcreteLibfunc::New(_)) => {
@@ -310,6 +314,13 @@ pub fn core_libfunc_postcost<Ops: CostOperations, InfoProvider: InvocationCostIn
                 ]
             }
             BuiltinCostConcreteLibfunc::GetBuiltinCosts(_) => vec![ops.steps(3)],
+            // The cost of the function itself is paid by the gas builtin, rather than by the
+            // wallet.
+            BuiltinCostConcreteLibfunc::CouponBuy(_) => vec![
+                ops.const_cost(ConstCost { steps: 3, holes: 0, range_checks: 1 }),
+                ops.const_cost(ConstCost { steps: 4, holes: 0, range_checks: 1 }),
+            ],
+            BuiltinCostConcreteLibfunc::CouponRefund(_) => vec![ops.steps(0)],
         },
         CoreConcreteLibfunc::StarkNet(libfunc) => starknet_libfunc_cost_base(ops, libfunc),
         CoreConcreteLibfunc::Nullable(libfunc) => match libfunc {
@@ -437,6 +448,9 @@ fn u128_libfunc_cost<Ops: CostOperations>(
         Uint128Concrete::Divmod(_) => {
             vec![ops.const_cost(ConstCost { steps: 11, holes: 0, range_checks: 4 })]
         }
+        Uint128Concrete::WrappingAdd(_) => {
+            vec![ops.const_cost(ConstCost { steps: 5, holes: 0, range_checks: 1 })]
+        }
         Uint128Concrete::WideMul(_) => {
             vec![ops.const_cost(ConstCost { steps: 23, holes: 0, range_checks: 9 })]
         }
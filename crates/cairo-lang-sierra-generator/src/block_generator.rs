@@ -16,7 +16,7 @@ use crate::utils::{
     branch_align_libfunc_id, const_libfunc_id_by_type, drop_libfunc_id, dup_libfunc_id,
     enum_init_libfunc_id, get_concrete_libfunc_id, jump_libfunc_id, jump_statement,
     match_enum_libfunc_id, return_statement, simple_statement, struct_construct_libfunc_id,
-    struct_deconstruct_libfunc_id,
+    struct_destructure_libfunc_id,
 };
 
 /// Generates Sierra code for the body of  given [lowering::FlatBlock].
@@ -461,11 +461,10 @@ fn generate_statement_struct_destructure_code(
     let input =
         add_dup_statement(context, statement_location, 0, &statement.input, &mut statements)?;
 
+    let libfunc_id =
+        struct_destructure_libfunc_id(context.get_db(), context.get_var_type(statement.input))?;
     statements.push(simple_statement(
-        struct_deconstruct_libfunc_id(
-            context.get_db(),
-            context.get_variable_sierra_type(statement.input)?,
-        ),
+        libfunc_id,
         &[input],
         &context.get_sierra_variables(&statement.outputs),
     ));
@@ -1,7 +1,6 @@
 use cairo_lang_lowering::db::LoweringGroup;
 use cairo_lang_semantic::test_utils::setup_test_function;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
-use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
 
 use crate::block_generator::generate_block_body_code;
 use crate::expr_generator_context::ExprGeneratorContext;
@@ -51,7 +50,7 @@ fn block_generator_test(inputs: &OrderedHashMap<String, String>) -> OrderedHashM
     let block = &lowered.blocks[block_id];
 
     // Generate (pre-)Sierra statements.
-    let lifetime = find_variable_lifetime(&lowered, &OrderedHashSet::default())
+    let lifetime = find_variable_lifetime(&lowered, &OrderedHashMap::default())
         .expect("Failed to retrieve lifetime information.");
     let mut expr_generator_context =
         ExprGeneratorContext::new(db, &lowered, test_function.concrete_function_id, &lifetime);
@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use cairo_lang_defs::ids::ModuleId;
 use cairo_lang_diagnostics::Maybe;
 use cairo_lang_filesystem::ids::CrateId;
 use cairo_lang_lowering::db::LoweringGroup;
@@ -8,7 +9,8 @@ use cairo_lang_semantic::corelib::get_core_ty_by_name;
 use cairo_lang_semantic::{ConcreteFunctionWithBodyId, GenericArgumentId, Mutability};
 use cairo_lang_sierra::extensions::lib_func::SierraApChange;
 use cairo_lang_sierra::extensions::{ConcreteType, GenericTypeEx};
-use cairo_lang_sierra::ids::ConcreteTypeId;
+use cairo_lang_sierra::ids::{ConcreteTypeId, GenericLibfuncId};
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
 use cairo_lang_utils::Upcast;
 
 use crate::program_generator::{self};
@@ -73,6 +75,13 @@ pub trait SierraGenGroup: LoweringGroup + Upcast<dyn LoweringGroup> {
         &self,
         function_id: ConcreteFunctionWithBodyId,
     ) -> Maybe<Arc<pre_sierra::Function>>;
+    /// Returns the generic libfuncs used by the Sierra code of a given function, in order of first
+    /// use.
+    #[salsa::invoke(program_generator::function_with_body_used_libfuncs)]
+    fn function_with_body_used_libfuncs(
+        &self,
+        function_id: ConcreteFunctionWithBodyId,
+    ) -> Maybe<OrderedHashSet<GenericLibfuncId>>;
 
     /// Returns `true` if the function calls (possibly indirectly) itself, or if it calls (possibly
     /// indirectly) such a function. For example, if f0 calls f1, f1 calls f2, f2 calls f3, and f3
@@ -99,6 +108,14 @@ pub trait SierraGenGroup: LoweringGroup + Upcast<dyn LoweringGroup> {
         &self,
         requested_crate_ids: Vec<CrateId>,
     ) -> Maybe<Arc<cairo_lang_sierra::program::Program>>;
+
+    /// Returns the [cairo_lang_sierra::program::Program] object of the free functions of the
+    /// requested module (not including its submodules).
+    #[salsa::invoke(program_generator::get_sierra_program_for_module)]
+    fn get_sierra_program_for_module(
+        &self,
+        module_id: ModuleId,
+    ) -> Maybe<Arc<cairo_lang_sierra::program::Program>>;
 }
 
 fn get_function_signature(
@@ -8,14 +8,13 @@ use cairo_lang_diagnostics::Maybe;
 use cairo_lang_semantic::ConcreteFunctionWithBodyId;
 use cairo_lang_sierra::ids::ConcreteLibfuncId;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
-use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
 use {cairo_lang_lowering as lowering, cairo_lang_semantic as semantic};
 
 use crate::block_generator::generate_block_code;
 use crate::db::SierraGenGroup;
 use crate::expr_generator_context::ExprGeneratorContext;
 use crate::lifetime::{find_variable_lifetime, SierraGenVar};
-use crate::local_variables::find_local_variables;
+use crate::local_variables::{find_local_variable_cells, find_local_variables};
 use crate::pre_sierra::{self, Statement};
 use crate::store_variables::{add_store_statements, LibfuncInfo, LocalVariables};
 use crate::utils::{
@@ -56,8 +55,11 @@ fn get_function_code(
     // Find the local variables.
     let local_variables = find_local_variables(db, lowered_function)?;
 
+    // Find the local variables that can share a cell.
+    let local_variable_cells = find_local_variable_cells(lowered_function, &local_variables)?;
+
     // Get lifetime information.
-    let lifetime = find_variable_lifetime(lowered_function, &local_variables)?;
+    let lifetime = find_variable_lifetime(lowered_function, &local_variable_cells)?;
 
     let mut context = ExprGeneratorContext::new(db, lowered_function, function_id, &lifetime);
 
@@ -80,7 +82,7 @@ fn get_function_code(
     let mut statements: Vec<pre_sierra::Statement> = vec![label];
 
     let (sierra_local_variables, allocate_local_statements) =
-        allocate_local_variables(&mut context, &local_variables)?;
+        allocate_local_variables(&mut context, &local_variable_cells)?;
     statements.extend(allocate_local_statements);
 
     let prolog_size = statements.len();
@@ -124,23 +126,26 @@ fn get_function_code(
 /// * A list of Sierra statements.
 fn allocate_local_variables(
     context: &mut ExprGeneratorContext<'_>,
-    local_variables: &OrderedHashSet<lowering::VariableId>,
+    local_variables: &OrderedHashMap<lowering::VariableId, lowering::VariableId>,
 ) -> Maybe<(LocalVariables, Vec<Statement>)> {
     let mut statements: Vec<pre_sierra::Statement> = vec![];
     let mut sierra_local_variables =
         OrderedHashMap::<cairo_lang_sierra::ids::VarId, cairo_lang_sierra::ids::VarId>::default();
-    for lowering_var_id in local_variables.iter() {
+    for (lowering_var_id, cell_var_id) in local_variables.iter() {
         let sierra_var_id = context.get_sierra_variable(*lowering_var_id);
         let uninitialized_local_var_id =
-            context.get_sierra_variable(SierraGenVar::UninitializedLocal(*lowering_var_id));
-        statements.push(simple_statement(
-            alloc_local_libfunc_id(
-                context.get_db(),
-                context.get_variable_sierra_type(*lowering_var_id)?,
-            ),
-            &[],
-            &[uninitialized_local_var_id.clone()],
-        ));
+            context.get_sierra_variable(SierraGenVar::UninitializedLocal(*cell_var_id));
+        // Allocate the cell only once, for the variable that owns it.
+        if lowering_var_id == cell_var_id {
+            statements.push(simple_statement(
+                alloc_local_libfunc_id(
+                    context.get_db(),
+                    context.get_variable_sierra_type(*lowering_var_id)?,
+                ),
+                &[],
+                std::slice::from_ref(&uninitialized_local_var_id),
+            ));
+        }
 
         sierra_local_variables.insert(sierra_var_id, uninitialized_local_var_id);
     }
@@ -2,6 +2,7 @@
 #[path = "lifetime_test.rs"]
 mod test;
 
+use std::cmp::Reverse;
 use std::fmt::Debug;
 
 use cairo_lang_diagnostics::Maybe;
@@ -54,6 +55,15 @@ pub enum SierraGenVar {
     UninitializedLocal(VariableId),
 }
 
+impl SierraGenVar {
+    /// Returns the lowering variable this variable is defined by.
+    fn lowering_var(&self) -> VariableId {
+        match self {
+            Self::LoweringVar(var) | Self::UninitializedLocal(var) => *var,
+        }
+    }
+}
+
 impl From<VariableId> for SierraGenVar {
     fn from(var: VariableId) -> Self {
         SierraGenVar::LoweringVar(var)
@@ -81,6 +91,9 @@ pub struct VariableLifetimeResult {
     /// this means that the last use was in `block.end`.
     pub last_use: OrderedHashSet<UseLocation>,
     /// A map from [DropLocation] to the list of variables that should be dropped at this location.
+    ///
+    /// The variables of each location are ordered in reverse definition order, like stack
+    /// unwinding.
     pub drops: OrderedHashMap<DropLocation, Vec<SierraGenVar>>,
 }
 impl VariableLifetimeResult {
@@ -96,9 +109,12 @@ impl VariableLifetimeResult {
 
 /// Given the lowering of a function, returns lifetime information for all the variables.
 /// See [VariableLifetimeResult].
+///
+/// `local_vars` is a map from the local variables to the local variable whose cell they use
+/// (see [find_local_variable_cells](crate::local_variables::find_local_variable_cells)).
 pub fn find_variable_lifetime(
     lowered_function: &FlatLowered,
-    local_vars: &OrderedHashSet<VariableId>,
+    local_vars: &OrderedHashMap<VariableId, VariableId>,
 ) -> Maybe<VariableLifetimeResult> {
     let mut context = VariableLifetimeContext {
         lowered_function,
@@ -110,13 +126,17 @@ pub fn find_variable_lifetime(
     let root_block_id = lowered_function.root?;
     inner_find_variable_lifetime(&mut context, root_block_id, &mut state);
 
+    // Variables are allocated in definition order, so a higher index means a later definition.
+    for (_, vars) in context.res.drops.iter_mut() {
+        vars.sort_by_key(|var| Reverse(var.lowering_var().index()));
+    }
     Ok(context.res)
 }
 
 /// Context information for [inner_find_variable_lifetime] and its helper functions.
 struct VariableLifetimeContext<'a> {
     lowered_function: &'a FlatLowered,
-    local_vars: &'a OrderedHashSet<VariableId>,
+    local_vars: &'a OrderedHashMap<VariableId, VariableId>,
     res: VariableLifetimeResult,
 
     // A mapping BlockId to the life time state at the begging of that block.
@@ -282,10 +302,10 @@ impl VariableLifetimeState {
                 self.used_variables.swap_remove(&sierra_gen_var);
             }
 
-            if context.local_vars.contains(var_id) {
+            if let Some(cell_var_id) = context.local_vars.get(var_id) {
                 // When a local variable is defined, the corresponding uninitialized variable is
                 // used (by the `store_local` libfunc).
-                self.used_variables.insert(SierraGenVar::UninitializedLocal(*var_id));
+                self.used_variables.insert(SierraGenVar::UninitializedLocal(*cell_var_id));
             }
         }
     }
@@ -6,7 +6,7 @@ use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use itertools::Itertools;
 
 use super::find_variable_lifetime;
-use crate::local_variables::find_local_variables;
+use crate::local_variables::{find_local_variable_cells, find_local_variables};
 use crate::test_utils::SierraGenDatabaseForTesting;
 
 cairo_lang_test_utils::test_file_test!(
@@ -51,8 +51,11 @@ fn check_variable_lifetime(
     let lowered_str = format!("{:?}", lowered_function.debug(&lowered_formatter));
 
     let local_variables = find_local_variables(db, lowered_function).unwrap();
-    let find_variable_lifetime_res = find_variable_lifetime(lowered_function, &local_variables)
-        .expect("find_variable_lifetime failed unexpectedly");
+    let local_variable_cells =
+        find_local_variable_cells(lowered_function, &local_variables).unwrap();
+    let find_variable_lifetime_res =
+        find_variable_lifetime(lowered_function, &local_variable_cells)
+            .expect("find_variable_lifetime failed unexpectedly");
     let last_use_str = find_variable_lifetime_res
         .last_use
         .iter()
@@ -3,7 +3,6 @@
 mod test;
 
 use cairo_lang_diagnostics::Maybe;
-use cairo_lang_lowering as lowering;
 use cairo_lang_lowering::{BlockId, VariableId};
 use cairo_lang_sierra::extensions::lib_func::OutputVarInfo;
 use cairo_lang_sierra::extensions::OutputVarReferenceInfo;
@@ -11,12 +10,13 @@ use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
 use itertools::{zip_eq, Itertools};
 use lowering::FlatLowered;
+use {cairo_lang_lowering as lowering, cairo_lang_semantic as semantic};
 
 use crate::db::SierraGenGroup;
 use crate::replace_ids::{DebugReplacer, SierraIdReplacer};
 use crate::utils::{
     enum_init_libfunc_id, get_concrete_libfunc_id, get_libfunc_signature, match_enum_libfunc_id,
-    statement_outputs, struct_construct_libfunc_id, struct_deconstruct_libfunc_id,
+    statement_outputs, struct_construct_libfunc_id, struct_destructure_libfunc_id,
 };
 
 /// Given the lowering of a function, returns the set of variables which should be stored as local
@@ -35,6 +35,150 @@ pub fn find_local_variables(
     Ok(res)
 }
 
+/// Given the lowering of a function and its local variables, finds the local variables that can
+/// share a cell with another local variable.
+///
+/// Since the arms of a match are mutually exclusive, a local variable defined inside one arm may
+/// reuse the cell of a local variable of the same type defined inside a sibling arm.
+/// Variables that are live past the merge of the match (namely, remapped at the end of an arm) are
+/// never shared.
+///
+/// Returns a map from each local variable to the local variable whose cell it uses (which is the
+/// variable itself, if it does not reuse the cell of another variable).
+pub fn find_local_variable_cells(
+    lowered_function: &FlatLowered,
+    local_variables: &OrderedHashSet<VariableId>,
+) -> Maybe<OrderedHashMap<VariableId, VariableId>> {
+    let mut cells = OrderedHashMap::<VariableId, VariableId>::default();
+    inner_find_local_variable_cells(
+        lowered_function,
+        local_variables,
+        lowered_function.root?,
+        &mut cells,
+    );
+    Ok(local_variables
+        .iter()
+        .map(|var_id| (*var_id, cells.get(var_id).copied().unwrap_or(*var_id)))
+        .collect())
+}
+
+/// Helper function for [find_local_variable_cells].
+///
+/// Visits the matches in the given block and its sub-blocks, outer matches first, and assigns
+/// shared cells to the local variables of their arms.
+fn inner_find_local_variable_cells(
+    lowered_function: &FlatLowered,
+    local_variables: &OrderedHashSet<VariableId>,
+    block_id: BlockId,
+    res: &mut OrderedHashMap<VariableId, VariableId>,
+) {
+    let block = &lowered_function.blocks[block_id];
+    for statement in &block.statements {
+        let arm_blocks = match statement {
+            lowering::Statement::MatchExtern(lowering::StatementMatchExtern { arms, .. })
+            | lowering::Statement::MatchEnum(lowering::StatementMatchEnum { arms, .. }) => {
+                arms.iter().map(|(_, block_id)| *block_id).collect_vec()
+            }
+            _ => continue,
+        };
+
+        // For each arm, the local variables of each type which were not assigned a cell yet.
+        // Variables that are defined in the same arm never share a cell.
+        let arms_local_variables = arm_blocks
+            .iter()
+            .map(|arm_block_id| {
+                let mut vars = OrderedHashMap::<semantic::TypeId, Vec<VariableId>>::default();
+                for var_id in
+                    find_arm_local_variables(lowered_function, local_variables, *arm_block_id)
+                {
+                    if !res.contains_key(&var_id) {
+                        vars.entry(lowered_function.variables[var_id].ty).or_default().push(var_id);
+                    }
+                }
+                vars
+            })
+            .collect_vec();
+
+        // The first available variable of a given type in each of the arms share a cell, and
+        // so on.
+        let mut cells = OrderedHashMap::<(semantic::TypeId, usize), VariableId>::default();
+        for arm_local_variables in &arms_local_variables {
+            for (ty, vars) in arm_local_variables.iter() {
+                for (idx, var_id) in vars.iter().enumerate() {
+                    let cell = *cells.entry((*ty, idx)).or_insert(*var_id);
+                    res.insert(*var_id, cell);
+                }
+            }
+        }
+
+        for arm_block_id in arm_blocks {
+            inner_find_local_variable_cells(lowered_function, local_variables, arm_block_id, res);
+        }
+    }
+
+    if let lowering::FlatBlockEnd::Fallthrough(target_block_id, _) = &block.end {
+        inner_find_local_variable_cells(lowered_function, local_variables, *target_block_id, res);
+    }
+}
+
+/// Returns the local variables that are defined in the arm starting at the given block, and are
+/// not live past the merge of the match.
+fn find_arm_local_variables(
+    lowered_function: &FlatLowered,
+    local_variables: &OrderedHashSet<VariableId>,
+    arm_block_id: BlockId,
+) -> OrderedHashSet<VariableId> {
+    let mut defined_vars = OrderedHashSet::<VariableId>::default();
+    collect_defined_variables(lowered_function, arm_block_id, &mut defined_vars);
+
+    // Find the variables that are remapped at the end of the arm.
+    let mut block_id = arm_block_id;
+    let remapped_vars: OrderedHashSet<VariableId> = loop {
+        match &lowered_function.blocks[block_id].end {
+            lowering::FlatBlockEnd::Fallthrough(target_block_id, _) => {
+                block_id = *target_block_id;
+            }
+            lowering::FlatBlockEnd::Callsite(remapping)
+            | lowering::FlatBlockEnd::Goto(_, remapping) => {
+                break remapping.values().copied().collect();
+            }
+            lowering::FlatBlockEnd::Return(_) | lowering::FlatBlockEnd::Unreachable => {
+                break OrderedHashSet::default();
+            }
+        }
+    };
+    defined_vars
+        .iter()
+        .copied()
+        .filter(|var_id| local_variables.contains(var_id) && !remapped_vars.contains(var_id))
+        .collect()
+}
+
+/// Collects the variables defined in the given block, including the variables defined in the
+/// arms of its matches.
+/// Blocks that are reached through [lowering::FlatBlockEnd::Goto] are not visited.
+fn collect_defined_variables(
+    lowered_function: &FlatLowered,
+    block_id: BlockId,
+    res: &mut OrderedHashSet<VariableId>,
+) {
+    let block = &lowered_function.blocks[block_id];
+    res.extend(block.inputs.iter().copied());
+    for statement in &block.statements {
+        res.extend(statement_outputs(statement, lowered_function));
+        if let lowering::Statement::MatchExtern(lowering::StatementMatchExtern { arms, .. })
+        | lowering::Statement::MatchEnum(lowering::StatementMatchEnum { arms, .. }) = statement
+        {
+            for (_, arm_block_id) in arms {
+                collect_defined_variables(lowered_function, *arm_block_id, res);
+            }
+        }
+    }
+    if let lowering::FlatBlockEnd::Fallthrough(target_block_id, _) = &block.end {
+        collect_defined_variables(lowered_function, *target_block_id, res);
+    }
+}
+
 /// Information about a block that we reach through Goto or Fallthrough.
 #[derive(Clone, Debug)]
 struct BlockInfo {
@@ -134,14 +278,15 @@ fn inner_find_local_variables(
                 );
             }
             lowering::Statement::StructDestructure(statement_struct_destructure) => {
-                let ty = ctx.db.get_concrete_type_id(
+                let libfunc_id = struct_destructure_libfunc_id(
+                    ctx.db,
                     ctx.lowered_function.variables[statement_struct_destructure.input].ty,
                 )?;
                 handle_function_call(
                     ctx.db,
                     &mut state,
                     &mut known_ap_change,
-                    struct_deconstruct_libfunc_id(ctx.db, ty),
+                    libfunc_id,
                     &[statement_struct_destructure.input],
                     &statement_struct_destructure.outputs,
                 );
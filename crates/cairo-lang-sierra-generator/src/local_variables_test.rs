@@ -7,7 +7,9 @@ use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
 use itertools::Itertools;
 use lowering::VariableId;
 
-use super::{inner_find_local_variables, FindLocalsContext, LocalVariablesState};
+use super::{
+    find_local_variable_cells, inner_find_local_variables, FindLocalsContext, LocalVariablesState,
+};
 use crate::function_generator_test_utils::test_function_generator;
 use crate::test_utils::SierraGenDatabaseForTesting;
 
@@ -65,6 +67,19 @@ fn check_find_local_variables(
     let local_variables_str =
         res.iter().map(|var_id| format!("{:?}", var_id.debug(&lowered_formatter))).join(", ");
 
+    let shared_cells_str = find_local_variable_cells(lowered_function, &res)
+        .unwrap()
+        .iter()
+        .filter(|(var_id, cell_var_id)| var_id != cell_var_id)
+        .map(|(var_id, cell_var_id)| {
+            format!(
+                "{:?} -> {:?}",
+                var_id.debug(&lowered_formatter),
+                cell_var_id.debug(&lowered_formatter)
+            )
+        })
+        .join(", ");
+
     let block_infos_str = ctx
         .block_infos
         .iter()
@@ -77,6 +92,7 @@ fn check_find_local_variables(
         ("lowering_format".into(), lowered_str),
         ("local_variables".into(), local_variables_str),
         ("block_infos".into(), block_infos_str),
+        ("shared_cells".into(), shared_cells_str),
     ])
 }
 
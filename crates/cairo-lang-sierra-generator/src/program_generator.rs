@@ -1,13 +1,14 @@
 use std::collections::{HashSet, VecDeque};
 use std::sync::Arc;
 
+use cairo_lang_defs::ids::ModuleId;
 use cairo_lang_diagnostics::{skip_diagnostic, Maybe, ToMaybe};
 use cairo_lang_filesystem::ids::CrateId;
 use cairo_lang_semantic::ConcreteFunctionWithBodyId;
 use cairo_lang_sierra::extensions::core::CoreLibfunc;
 use cairo_lang_sierra::extensions::lib_func::SierraApChange;
 use cairo_lang_sierra::extensions::GenericLibfuncEx;
-use cairo_lang_sierra::ids::{ConcreteLibfuncId, ConcreteTypeId};
+use cairo_lang_sierra::ids::{ConcreteLibfuncId, ConcreteTypeId, GenericLibfuncId};
 use cairo_lang_sierra::program;
 use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
 use cairo_lang_utils::try_extract_matches;
@@ -58,6 +59,18 @@ fn collect_used_libfuncs(
         .collect()
 }
 
+/// Query implementation of [SierraGenGroup::function_with_body_used_libfuncs].
+pub fn function_with_body_used_libfuncs(
+    db: &dyn SierraGenGroup,
+    function_id: ConcreteFunctionWithBodyId,
+) -> Maybe<OrderedHashSet<GenericLibfuncId>> {
+    let function = db.function_with_body_sierra(function_id)?;
+    Ok(collect_used_libfuncs(&function.body)
+        .into_iter()
+        .map(|libfunc_id| db.lookup_intern_concrete_lib_func(libfunc_id).generic_id)
+        .collect())
+}
+
 /// Generates the list of [cairo_lang_sierra::program::TypeDeclaration] for the given list of
 /// [ConcreteTypeId].
 fn generate_type_declarations<'a>(
@@ -216,15 +229,31 @@ pub fn get_sierra_program(
     let mut requested_function_ids = vec![];
     for crate_id in requested_crate_ids {
         for module_id in db.crate_modules(crate_id).iter() {
-            for (free_func_id, _) in db.module_free_functions(*module_id)? {
-                // TODO(spapini): Search Impl functions.
-                if let Some(function) =
-                    ConcreteFunctionWithBodyId::from_no_generics_free(db.upcast(), free_func_id)
-                {
-                    requested_function_ids.push(function)
-                }
-            }
+            requested_function_ids.extend(module_requested_functions(db, *module_id)?);
         }
     }
     db.get_sierra_program_for_functions(requested_function_ids)
 }
+
+pub fn get_sierra_program_for_module(
+    db: &dyn SierraGenGroup,
+    module_id: ModuleId,
+) -> Maybe<Arc<cairo_lang_sierra::program::Program>> {
+    db.get_sierra_program_for_functions(module_requested_functions(db, module_id)?)
+}
+
+/// Returns the non-generic free functions of the given module, to be used as the roots of a Sierra
+/// program.
+fn module_requested_functions(
+    db: &dyn SierraGenGroup,
+    module_id: ModuleId,
+) -> Maybe<Vec<ConcreteFunctionWithBodyId>> {
+    // TODO(spapini): Search Impl functions.
+    Ok(db
+        .module_free_functions_ids(module_id)?
+        .into_iter()
+        .filter_map(|free_func_id| {
+            ConcreteFunctionWithBodyId::from_no_generics_free(db.upcast(), free_func_id)
+        })
+        .collect())
+}
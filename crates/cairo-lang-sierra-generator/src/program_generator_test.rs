@@ -1,5 +1,5 @@
 use cairo_lang_defs::db::DefsGroup;
-use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_defs::ids::{ModuleId, ModuleItemId};
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::ConcreteFunctionWithBodyId;
 use cairo_lang_utils::try_extract_matches;
@@ -143,3 +143,64 @@ fn test_only_include_dependecies(func_name: &str, sierra_used_funcs: &[&str]) {
         sierra_used_funcs
     );
 }
+
+#[test]
+fn test_tuple_return_signature() {
+    let program = checked_compile_to_sierra(indoc! {"
+                fn foo(a: felt) -> (felt, felt) implicits(RangeCheck) nopanic {
+                    (a, a)
+                }
+            "});
+
+    // Implicits come first, and the tuple itself is returned as a single struct value.
+    let signature = &program.funcs[0].signature;
+    assert_eq!(
+        signature.ret_types.iter().map(|ty| ty.to_string()).collect_vec(),
+        vec!["RangeCheck", "Tuple<felt, felt>"]
+    );
+    assert_eq!(
+        signature.param_types.iter().map(|ty| ty.to_string()).collect_vec(),
+        vec!["RangeCheck", "felt"]
+    );
+}
+
+#[test]
+fn test_sierra_program_for_module() {
+    let (db, crate_id) = setup_db_and_get_crate_id(indoc! {"
+        fn foo(a: felt) -> felt { bar(a) }
+        fn bar(a: felt) -> felt { a }
+    "});
+    let program = db.get_sierra_program_for_module(ModuleId::CrateRoot(crate_id)).unwrap();
+    assert_eq!(program, db.get_sierra_program(vec![crate_id]).unwrap());
+    assert_eq!(
+        replace_sierra_ids_in_program(&db, &program)
+            .funcs
+            .into_iter()
+            .map(|func| func.id.to_string())
+            .collect_vec(),
+        vec!["test::foo", "test::bar"]
+    );
+}
+
+#[test]
+fn test_function_with_body_used_libfuncs() {
+    let (db, crate_id) = setup_db_and_get_crate_id(indoc! {"
+        fn foo() -> Nullable::<()> {
+            into_nullable(into_box(()))
+        }
+    "});
+    let foo_id = try_extract_matches!(
+        db.module_item_by_name(ModuleId::CrateRoot(crate_id), "foo".into()).unwrap().unwrap(),
+        ModuleItemId::FreeFunction
+    )
+    .unwrap();
+    let function_id = ConcreteFunctionWithBodyId::from_no_generics_free(&db, foo_id).unwrap();
+    assert_eq!(
+        db.function_with_body_used_libfuncs(function_id)
+            .unwrap()
+            .into_iter()
+            .map(|libfunc_id| libfunc_id.to_string())
+            .collect_vec(),
+        vec!["struct_construct", "store_temp", "into_box", "into_nullable", "rename"]
+    );
+}
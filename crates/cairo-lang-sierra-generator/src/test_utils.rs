@@ -109,6 +109,27 @@ pub fn setup_db_and_get_crate_id(
     (db_val, crate_id)
 }
 
+/// Asserts that the `droppable` and `duplicatable` flags of the variables of a lowered function
+/// agree with the [TypeInfo](cairo_lang_sierra::extensions::types::TypeInfo) of the Sierra types
+/// they map to.
+///
+/// Since `Drop` and `Copy` are opt-in at the semantic level, a variable may be stricter than its
+/// Sierra type, but any flag set on the variable must also be set on the Sierra type.
+pub fn assert_variable_flags_match_type_info(
+    db: &dyn SierraGenGroup,
+    lowered: &lowering::FlatLowered,
+) {
+    for (_, var) in lowered.variables.iter() {
+        let type_info = db.get_type_info(db.get_concrete_type_id(var.ty).unwrap()).unwrap();
+        let ty = var.ty.format(db.upcast());
+        assert!(!var.droppable || type_info.droppable, "`{ty}` is not droppable in Sierra.");
+        assert!(
+            !var.duplicatable || type_info.duplicatable,
+            "`{ty}` is not duplicatable in Sierra."
+        );
+    }
+}
+
 /// Generates a dummy statement with the given name, inputs and outputs.
 pub fn dummy_simple_statement(
     db: &dyn SierraGenGroup,
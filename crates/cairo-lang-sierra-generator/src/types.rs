@@ -1,3 +1,7 @@
+#[cfg(test)]
+#[path = "types_test.rs"]
+mod test;
+
 use cairo_lang_diagnostics::Maybe;
 use cairo_lang_semantic as semantic;
 use cairo_lang_semantic::items::enm::SemanticEnumEx;
@@ -0,0 +1,31 @@
+use cairo_lang_lowering::db::LoweringGroup;
+use cairo_lang_semantic::test_utils::setup_test_function;
+
+use crate::db::SierraGenGroup;
+use crate::test_utils::{assert_variable_flags_match_type_info, SierraGenDatabaseForTesting};
+
+#[test]
+fn variable_flags_match_type_info() {
+    let db = &mut SierraGenDatabaseForTesting::default();
+    let test_function = setup_test_function(
+        db,
+        "fn foo(a: A, b: B) -> (A, B) { let c = b; (a, c) }",
+        "foo",
+        indoc::indoc! {"
+            struct A { dict: DictFeltTo::<felt>, x: felt }
+            #[derive(Copy, Drop)]
+            struct B { x: felt }
+        "},
+    )
+    .unwrap();
+    let lowered =
+        db.concrete_function_with_body_lowered(test_function.concrete_function_id).unwrap();
+
+    // The non-droppable struct is neither droppable nor duplicatable in Sierra either.
+    let a_var = lowered.variables.iter().next().unwrap().1;
+    assert!(!a_var.droppable && !a_var.duplicatable);
+    let a_type_info = db.get_type_info(db.get_concrete_type_id(a_var.ty).unwrap()).unwrap();
+    assert!(!a_type_info.droppable && !a_type_info.duplicatable);
+
+    assert_variable_flags_match_type_info(db, &lowered);
+}
@@ -1,4 +1,5 @@
-use cairo_lang_semantic::corelib::get_const_libfunc_name_by_type;
+use cairo_lang_diagnostics::Maybe;
+use cairo_lang_semantic::corelib::{get_const_libfunc_name_by_type, unwrap_snapshot_ty};
 use cairo_lang_sierra::extensions::core::CoreLibfunc;
 use cairo_lang_sierra::extensions::lib_func::LibfuncSignature;
 use cairo_lang_sierra::extensions::GenericLibfuncEx;
@@ -89,6 +90,27 @@ pub fn struct_deconstruct_libfunc_id(
     get_libfunc_id_with_generic_arg(db, "struct_deconstruct", ty)
 }
 
+pub fn struct_snapshot_deconstruct_libfunc_id(
+    db: &dyn SierraGenGroup,
+    ty: cairo_lang_sierra::ids::ConcreteTypeId,
+) -> cairo_lang_sierra::ids::ConcreteLibfuncId {
+    get_libfunc_id_with_generic_arg(db, "struct_snapshot_deconstruct", ty)
+}
+
+/// Returns the libfunc destructuring a value of the given type. A snapshot of a struct is
+/// deconstructed into snapshots of its members.
+pub fn struct_destructure_libfunc_id(
+    db: &dyn SierraGenGroup,
+    ty: semantic::TypeId,
+) -> Maybe<cairo_lang_sierra::ids::ConcreteLibfuncId> {
+    Ok(match unwrap_snapshot_ty(db.upcast(), ty) {
+        Some(struct_ty) => {
+            struct_snapshot_deconstruct_libfunc_id(db, db.get_concrete_type_id(struct_ty)?)
+        }
+        None => struct_deconstruct_libfunc_id(db, db.get_concrete_type_id(ty)?),
+    })
+}
+
 pub fn enum_init_libfunc_id(
     db: &dyn SierraGenGroup,
     ty: cairo_lang_sierra::ids::ConcreteTypeId,
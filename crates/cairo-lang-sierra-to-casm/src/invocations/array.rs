@@ -8,6 +8,10 @@ use crate::invocations::{
     add_input_variables, get_non_fallthrough_statement_id, CostValidationInfo,
 };
 
+#[cfg(test)]
+#[path = "array_test.rs"]
+mod test;
+
 /// Builds instructions for Sierra array operations.
 pub fn build(
     libfunc: &ArrayConcreteLibfunc,
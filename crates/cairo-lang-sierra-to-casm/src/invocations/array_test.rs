@@ -0,0 +1,26 @@
+use cairo_lang_casm::ap_change::ApChange;
+use cairo_lang_casm::casm;
+use test_log::test;
+
+use crate::invocations::test_utils::{
+    compile_libfunc, ReducedBranchChanges, ReducedCompiledInvocation,
+};
+use crate::ref_expr;
+
+#[test]
+fn test_array_append() {
+    assert_eq!(
+        compile_libfunc(
+            "array_append<felt>",
+            vec![ref_expr!([fp - 4], [fp - 3]), ref_expr!([ap - 1])]
+        ),
+        ReducedCompiledInvocation {
+            instructions: casm! {[ap - 1] = [[fp - 3] + 0];}.instructions,
+            relocations: vec![],
+            results: vec![ReducedBranchChanges {
+                refs: vec![ref_expr!([fp - 4], [fp - 3] + 1)],
+                ap_change: ApChange::Known(0)
+            }]
+        }
+    );
+}
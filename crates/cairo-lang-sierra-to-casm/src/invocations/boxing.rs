@@ -62,9 +62,7 @@ fn build_unbox(
         .to_deref()
         .ok_or(InvocationError::InvalidReferenceExpressionForArgument)?;
     Ok(builder.build_only_reference_changes(
-        [ReferenceExpression {
-            cells: (0..size).map(|idx| CellExpression::DoubleDeref(operand, idx)).collect(),
-        }]
-        .into_iter(),
+        [ReferenceExpression::contiguous(CellExpression::DoubleDeref(operand, 0), size)]
+            .into_iter(),
     ))
 }
@@ -1,8 +1,9 @@
 use cairo_lang_casm::builder::CasmBuilder;
-use cairo_lang_casm::cell_expression::CellExpression;
-use cairo_lang_casm::operand::{CellRef, Register};
+use cairo_lang_casm::cell_expression::{CellExpression, CellOperator};
+use cairo_lang_casm::operand::{CellRef, DerefOrImmediate, Register};
 use cairo_lang_casm::{casm, casm_build_extend};
 use cairo_lang_sierra::extensions::builtin_cost::{BuiltinCostConcreteLibfunc, CostTokenType};
+use cairo_lang_sierra::program::Function;
 use num_bigint::BigInt;
 
 use super::{CompiledInvocation, CompiledInvocationBuilder, InvocationError};
@@ -12,6 +13,10 @@ use crate::invocations::{
 use crate::references::ReferenceExpression;
 use crate::relocations::{Relocation, RelocationEntry};
 
+#[cfg(test)]
+#[path = "builtin_cost_test.rs"]
+mod test;
+
 /// Builds instructions for Sierra gas operations.
 pub fn build(
     libfunc: &BuiltinCostConcreteLibfunc,
@@ -20,6 +25,12 @@ pub fn build(
     match libfunc {
         BuiltinCostConcreteLibfunc::BuiltinGetGas(_) => build_builtin_get_gas(builder),
         BuiltinCostConcreteLibfunc::GetBuiltinCosts(_) => build_get_builtin_costs(builder),
+        BuiltinCostConcreteLibfunc::CouponBuy(libfunc) => {
+            build_coupon_buy(&libfunc.function, builder)
+        }
+        BuiltinCostConcreteLibfunc::CouponRefund(libfunc) => {
+            build_coupon_refund(&libfunc.function, builder)
+        }
     }
 }
 
@@ -131,3 +142,89 @@ fn build_get_builtin_costs(
         .into_iter(),
     ))
 }
+
+/// Returns the cost of the function a coupon is for.
+fn get_coupon_cost(
+    function: &Function,
+    builder: &CompiledInvocationBuilder<'_>,
+) -> Result<i64, InvocationError> {
+    builder
+        .program_info
+        .metadata
+        .gas_info
+        .function_costs
+        .get(&function.id)
+        .and_then(|costs| costs.get(&CostTokenType::Const))
+        .copied()
+        .ok_or(InvocationError::UnknownVariableData)
+}
+
+/// Handles the coupon buy invocation.
+fn build_coupon_buy(
+    function: &Function,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let requested_count = get_coupon_cost(function, &builder)?;
+    let [range_check, gas_counter] = builder.try_get_single_cells()?;
+
+    let failure_handle_statement_id = get_non_fallthrough_statement_id(&builder);
+
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(1) range_check;
+        deref gas_counter;
+    };
+
+    casm_build_extend! {casm_builder,
+        let orig_range_check = range_check;
+        tempvar has_enough_gas;
+        const requested_count_imm = requested_count;
+        hint TestLessThanOrEqual {lhs: requested_count_imm, rhs: gas_counter} into {dst: has_enough_gas};
+        jump HasEnoughGas if has_enough_gas != 0;
+        const gas_counter_fix = (BigInt::from(u128::MAX) + 1 - requested_count) as BigInt;
+        tempvar gas_diff = gas_counter + gas_counter_fix;
+        assert gas_diff = *(range_check++);
+        jump Failure;
+        HasEnoughGas:
+        tempvar updated_gas = gas_counter - requested_count_imm;
+        assert updated_gas = *(range_check++);
+    };
+
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [
+            ("Fallthrough", &[&[range_check], &[updated_gas], &[]], None),
+            ("Failure", &[&[range_check], &[gas_counter]], Some(failure_handle_statement_id)),
+        ],
+        CostValidationInfo {
+            range_check_info: Some((orig_range_check, range_check)),
+            extra_costs: None,
+        },
+    ))
+}
+
+/// Handles the coupon refund invocation.
+fn build_coupon_refund(
+    function: &Function,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let refund_count = get_coupon_cost(function, &builder)?;
+    let [gas_counter, _coupon] = builder.try_get_refs()?;
+    let gas_counter_value = gas_counter
+        .try_unpack_single()?
+        .to_deref()
+        .ok_or(InvocationError::InvalidReferenceExpressionForArgument)?;
+
+    Ok(builder.build_only_reference_changes(
+        [if refund_count == 0 {
+            ReferenceExpression::from_cell(CellExpression::Deref(gas_counter_value))
+        } else {
+            ReferenceExpression::from_cell(CellExpression::BinOp {
+                op: CellOperator::Add,
+                a: gas_counter_value,
+                b: DerefOrImmediate::Immediate(BigInt::from(refund_count)),
+            })
+        }]
+        .into_iter(),
+    ))
+}
@@ -0,0 +1,21 @@
+use cairo_lang_casm::ap_change::ApChange;
+use pretty_assertions::assert_eq;
+use test_log::test;
+
+use crate::invocations::test_utils::{compile_libfunc, ReducedBranchChanges};
+use crate::ref_expr;
+
+#[test]
+fn test_get_builtin_costs() {
+    let compiled = compile_libfunc("get_builtin_costs", vec![]);
+    // The `call` pushes two cells and the pointer computation pushes one more, matching the
+    // `Known(3)` ap change of `get_builtin_costs` in the ap change computation.
+    assert_eq!(compiled.instructions.len(), 2);
+    assert_eq!(
+        compiled.results,
+        vec![ReducedBranchChanges {
+            refs: vec![ref_expr!([[ap - 1]])],
+            ap_change: ApChange::Known(3)
+        }]
+    );
+}
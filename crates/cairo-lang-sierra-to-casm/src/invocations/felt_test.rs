@@ -59,3 +59,24 @@ fn test_jump_nz() {
         }
     );
 }
+
+#[test]
+fn test_jump_nz_fp_based() {
+    assert_eq!(
+        compile_libfunc("felt_is_zero", vec![ref_expr!([fp + 3])]),
+        ReducedCompiledInvocation {
+            instructions: casm! {jmp rel 0 if [fp + 3] != 0;}.instructions,
+            relocations: vec![RelocationEntry {
+                instruction_idx: 0,
+                relocation: Relocation::RelativeStatementId(StatementIdx(1))
+            }],
+            results: vec![
+                ReducedBranchChanges { refs: vec![], ap_change: ApChange::Known(0) },
+                ReducedBranchChanges {
+                    refs: vec![ref_expr!([fp + 3])],
+                    ap_change: ApChange::Known(0)
+                }
+            ]
+        }
+    );
+}
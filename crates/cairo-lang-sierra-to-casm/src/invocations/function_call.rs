@@ -31,11 +31,10 @@ pub fn build(
             .type_sizes
             .get(output_type)
             .ok_or(InvocationError::UnknownVariableData)?;
-        refs.push_front(ReferenceExpression {
-            cells: ((offset - size + 1)..(offset + 1))
-                .map(|i| CellExpression::Deref(CellRef { register: Register::AP, offset: i }))
-                .collect(),
-        });
+        refs.push_front(ReferenceExpression::contiguous(
+            CellExpression::Deref(CellRef { register: Register::AP, offset: offset - size + 1 }),
+            *size,
+        ));
         offset -= size;
     }
 
@@ -103,11 +103,10 @@ fn build_store_temp(
     Ok(builder.build(
         instructions,
         vec![],
-        [[ReferenceExpression {
-            cells: (-type_size..0)
-                .map(|i| CellExpression::Deref(CellRef { register: Register::AP, offset: i }))
-                .collect(),
-        }]
+        [[ReferenceExpression::contiguous(
+            CellExpression::Deref(CellRef { register: Register::AP, offset: -type_size }),
+            type_size,
+        )]
         .into_iter()]
         .into_iter(),
     ))
@@ -128,16 +127,10 @@ fn build_store_local(
     Ok(builder.build(
         instructions,
         vec![],
-        [[ReferenceExpression {
-            cells: (0..type_size)
-                .map(|i| {
-                    CellExpression::Deref(CellRef {
-                        register: Register::FP,
-                        offset: dst.offset + i,
-                    })
-                })
-                .collect(),
-        }]
+        [[ReferenceExpression::contiguous(
+            CellExpression::Deref(CellRef { register: Register::FP, offset: dst.offset }),
+            type_size,
+        )]
         .into_iter()]
         .into_iter(),
     ))
@@ -50,6 +50,8 @@ mod structure;
 mod uint;
 mod uint128;
 
+#[cfg(test)]
+mod test;
 #[cfg(test)]
 mod test_utils;
 
@@ -75,6 +77,8 @@ pub enum InvocationError {
     InvalidGenericArg,
     #[error("Invalid generic argument for libfunc.")]
     IntegerOverflow,
+    #[error("The outputs of a branch do not match the sizes of its signature types.")]
+    BranchMismatch { branch_idx: usize },
     #[error(transparent)]
     FrameStateError(#[from] FrameStateError),
 }
@@ -149,6 +153,24 @@ pub struct CompiledInvocation {
     pub environment: Environment,
 }
 
+/// Checks that the outputs of all the branches match the sizes of their signature types.
+/// This catches builders that produce differently shaped outputs than the libfunc declares.
+pub fn check_branch_changes_sizes(
+    type_sizes: &TypeSizeMap,
+    results: &[BranchChanges],
+) -> Result<(), InvocationError> {
+    for (branch_idx, branch_changes) in results.iter().enumerate() {
+        for reference in &branch_changes.refs {
+            if let Some(size) = type_sizes.get(&reference.ty) {
+                if reference.expression.cells.len() != *size as usize {
+                    return Err(InvocationError::BranchMismatch { branch_idx });
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
 /// Checks that the list of reference is contiguous on the stack and ends at ap - 1.
 /// This is the requirement for function call and return statements.
 pub fn check_references_on_stack(refs: &[ReferenceValue]) -> Result<(), InvocationError> {
@@ -447,9 +469,10 @@ pub fn compile_invocation(
     refs: &[ReferenceValue],
     environment: Environment,
 ) -> Result<CompiledInvocation, InvocationError> {
+    let type_sizes = program_info.type_sizes;
     let builder =
         CompiledInvocationBuilder { program_info, invocation, libfunc, idx, refs, environment };
-    match libfunc {
+    let compiled_invocation = match libfunc {
         // TODO(ilya, 10/10/2022): Handle type.
         CoreConcreteLibfunc::Felt(libfunc) => felt::build(libfunc, builder),
         CoreConcreteLibfunc::Bitwise(_) => bitwise::build(builder),
@@ -463,6 +486,7 @@ pub fn compile_invocation(
         CoreConcreteLibfunc::Array(libfunc) => array::build(libfunc, builder),
         CoreConcreteLibfunc::Drop(_) => misc::build_drop(builder),
         CoreConcreteLibfunc::Dup(_) => misc::build_dup(builder),
+        CoreConcreteLibfunc::SnapshotTake(_) => misc::build_dup(builder),
         CoreConcreteLibfunc::Mem(libfunc) => mem::build(libfunc, builder),
         CoreConcreteLibfunc::UnwrapNonZero(_) => misc::build_identity(builder),
         CoreConcreteLibfunc::FunctionCall(libfunc) => function_call::build(libfunc, builder),
@@ -477,7 +501,9 @@ pub fn compile_invocation(
         CoreConcreteLibfunc::StarkNet(libfunc) => starknet::build(libfunc, builder),
         CoreConcreteLibfunc::Nullable(libfunc) => nullable::build(libfunc, builder),
         CoreConcreteLibfunc::Debug(libfunc) => debug::build(libfunc, builder),
-    }
+    }?;
+    check_branch_changes_sizes(type_sizes, &compiled_invocation.results)?;
+    Ok(compiled_invocation)
 }
 
 /// A trait for views of the Complex ReferenceExpressions as specific data structures (e.g.
@@ -1,20 +1,27 @@
 use cairo_lang_casm::cell_expression::CellExpression;
 use cairo_lang_sierra::extensions::lib_func::SignatureAndTypeConcreteLibfunc;
 use cairo_lang_sierra::extensions::nullable::NullableConcreteLibfunc;
+use num_traits::Zero;
 
 use super::misc::build_identity;
 use super::{CompiledInvocation, CompiledInvocationBuilder, InvocationError};
 use crate::invocations::misc::build_is_zero;
 use crate::references::ReferenceExpression;
 
+#[cfg(test)]
+#[path = "nullable_test.rs"]
+mod test;
+
 /// Builds Casm instructions for Nullable operations.
 pub fn build(
     libfunc: &NullableConcreteLibfunc,
     builder: CompiledInvocationBuilder<'_>,
 ) -> Result<CompiledInvocation, InvocationError> {
+    // No catch-all arm: a new nullable libfunc should fail to compile here until it has a builder,
+    // rather than surface as [InvocationError::NotImplemented] at runtime.
     match libfunc {
         NullableConcreteLibfunc::Null(_) => build_nullable_null(builder),
-        NullableConcreteLibfunc::IntoNullable(_) => build_identity(builder),
+        NullableConcreteLibfunc::IntoNullable(_) => build_nullable_into_nullable(builder),
         NullableConcreteLibfunc::FromNullable(libfunc) => {
             build_nullable_from_nullable(builder, libfunc)
         }
@@ -31,7 +38,20 @@ fn build_nullable_null(
     ))
 }
 
-/// Builds Casm instructions for the `null()` libfunc.
+/// Builds Casm instructions for the `into_nullable()` libfunc.
+fn build_nullable_into_nullable(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    // A zero pointer would later be indistinguishable from `null`. A non-immediate box cannot be
+    // zero, as the Cairo-AIR guarantees that all memory accesses have address >= 1.
+    let [expr] = builder.try_get_refs()?;
+    if matches!(expr.try_unpack_single()?, CellExpression::Immediate(value) if value.is_zero()) {
+        return Err(InvocationError::InvalidReferenceExpressionForArgument);
+    }
+    build_identity(builder)
+}
+
+/// Builds Casm instructions for the `from_nullable()` libfunc.
 fn build_nullable_from_nullable(
     builder: CompiledInvocationBuilder<'_>,
     libfunc: &SignatureAndTypeConcreteLibfunc,
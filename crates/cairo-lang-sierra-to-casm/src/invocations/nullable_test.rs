@@ -0,0 +1,58 @@
+use cairo_lang_casm::ap_change::ApChange;
+use cairo_lang_casm::casm;
+use cairo_lang_sierra::program::StatementIdx;
+use pretty_assertions::assert_eq;
+use test_log::test;
+
+use crate::invocations::test_utils::{
+    compile_libfunc, try_compile_libfunc, ReducedBranchChanges, ReducedCompiledInvocation,
+};
+use crate::invocations::InvocationError;
+use crate::ref_expr;
+use crate::relocations::{Relocation, RelocationEntry};
+
+#[test]
+fn test_from_nullable() {
+    assert_eq!(
+        compile_libfunc("from_nullable<felt>", vec![ref_expr!([fp + 1])]),
+        ReducedCompiledInvocation {
+            instructions: casm! { jmp rel 0 if [fp + 1] != 0; }.instructions,
+            relocations: vec![RelocationEntry {
+                instruction_idx: 0,
+                relocation: Relocation::RelativeStatementId(StatementIdx(1))
+            }],
+            results: vec![
+                // The null branch has no outputs.
+                ReducedBranchChanges { refs: vec![], ap_change: ApChange::Known(0) },
+                // The non-null branch outputs the box pointer.
+                ReducedBranchChanges {
+                    refs: vec![ref_expr!([fp + 1])],
+                    ap_change: ApChange::Known(0)
+                }
+            ]
+        }
+    );
+}
+
+#[test]
+fn test_into_nullable() {
+    assert_eq!(
+        compile_libfunc("into_nullable<felt>", vec![ref_expr!([fp + 1])]),
+        ReducedCompiledInvocation {
+            instructions: vec![],
+            relocations: vec![],
+            results: vec![ReducedBranchChanges {
+                refs: vec![ref_expr!([fp + 1])],
+                ap_change: ApChange::Known(0)
+            }]
+        }
+    );
+}
+
+#[test]
+fn test_into_nullable_of_zero() {
+    assert_eq!(
+        try_compile_libfunc("into_nullable<felt>", vec![ref_expr!(0)]),
+        Err(InvocationError::InvalidReferenceExpressionForArgument)
+    );
+}
@@ -4,6 +4,10 @@ use cairo_lang_sierra::extensions::ConcreteLibfunc;
 use super::{CompiledInvocation, CompiledInvocationBuilder, InvocationError};
 use crate::references::ReferenceExpression;
 
+#[cfg(test)]
+#[path = "structure_test.rs"]
+mod test;
+
 /// Builds instructions for Sierra struct operations.
 pub fn build(
     libfunc: &StructConcreteLibfunc,
@@ -19,7 +23,8 @@ pub fn build(
                 .collect();
             Ok(builder.build_only_reference_changes([ReferenceExpression { cells }].into_iter()))
         }
-        StructConcreteLibfunc::Deconstruct(libfunc) => {
+        StructConcreteLibfunc::Deconstruct(libfunc)
+        | StructConcreteLibfunc::SnapshotDeconstruct(libfunc) => {
             let struct_type = &libfunc.param_signatures()[0].ty;
             let cells = &builder.try_get_refs::<1>()?[0].cells;
             if cells.len() != builder.program_info.type_sizes[struct_type] as usize {
@@ -0,0 +1,51 @@
+use cairo_lang_casm::ap_change::ApChange;
+use test_log::test;
+
+use crate::invocations::test_utils::{
+    compile_libfunc, ReducedBranchChanges, ReducedCompiledInvocation,
+};
+use crate::ref_expr;
+use crate::references::ReferenceExpression;
+
+/// The struct type `(felt, (), felt)`, wrapping a zero-sized member.
+const STRUCT_WITH_UNIT: &str = "Struct<ut@Triple, felt, Struct<ut@Unit>, felt>";
+
+#[test]
+fn test_struct_construct_with_zero_sized_member() {
+    assert_eq!(
+        compile_libfunc(
+            &format!("struct_construct<{STRUCT_WITH_UNIT}>"),
+            vec![ref_expr!([fp + 1]), ReferenceExpression { cells: vec![] }, ref_expr!([fp + 2])]
+        ),
+        ReducedCompiledInvocation {
+            instructions: vec![],
+            relocations: vec![],
+            results: vec![ReducedBranchChanges {
+                refs: vec![ref_expr!([fp + 1], [fp + 2])],
+                ap_change: ApChange::Known(0)
+            }]
+        }
+    );
+}
+
+#[test]
+fn test_struct_deconstruct_with_zero_sized_member() {
+    assert_eq!(
+        compile_libfunc(
+            &format!("struct_deconstruct<{STRUCT_WITH_UNIT}>"),
+            vec![ref_expr!([fp + 1], [fp + 2])]
+        ),
+        ReducedCompiledInvocation {
+            instructions: vec![],
+            relocations: vec![],
+            results: vec![ReducedBranchChanges {
+                refs: vec![
+                    ref_expr!([fp + 1]),
+                    ReferenceExpression { cells: vec![] },
+                    ref_expr!([fp + 2])
+                ],
+                ap_change: ApChange::Known(0)
+            }]
+        }
+    );
+}
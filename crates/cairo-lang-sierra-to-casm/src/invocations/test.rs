@@ -0,0 +1,40 @@
+use cairo_lang_casm::ap_change::ApChange;
+use cairo_lang_sierra::ids::ConcreteTypeId;
+use pretty_assertions::assert_eq;
+use test_log::test;
+
+use super::{check_branch_changes_sizes, BranchChanges, InvocationError};
+use crate::ref_expr;
+use crate::references::{ReferenceExpression, ReferenceValue};
+use crate::type_sizes::TypeSizeMap;
+
+/// Returns the changes of a branch with a single `felt` output.
+fn felt_branch_changes(expression: ReferenceExpression) -> BranchChanges {
+    BranchChanges {
+        refs: vec![ReferenceValue { expression, ty: ConcreteTypeId::from_string("felt") }],
+        ap_change: ApChange::Known(0),
+        gas_change: Default::default(),
+    }
+}
+
+#[test]
+fn test_check_branch_changes_sizes() {
+    let type_sizes = TypeSizeMap::from([(ConcreteTypeId::from_string("felt"), 1)]);
+    assert_eq!(
+        check_branch_changes_sizes(
+            &type_sizes,
+            &[felt_branch_changes(ref_expr!([fp + 1])), felt_branch_changes(ref_expr!([ap - 1]))]
+        ),
+        Ok(())
+    );
+    assert_eq!(
+        check_branch_changes_sizes(
+            &type_sizes,
+            &[
+                felt_branch_changes(ref_expr!([fp + 1])),
+                felt_branch_changes(ref_expr!([ap - 2], [ap - 1]))
+            ]
+        ),
+        Err(InvocationError::BranchMismatch { branch_idx: 1 })
+    );
+}
@@ -12,12 +12,12 @@ use cairo_lang_sierra::extensions::{
     ConcreteLibfunc, ConcreteType, GenericLibfuncEx, GenericTypeEx,
 };
 use cairo_lang_sierra::ids::{ConcreteTypeId, VarId};
-use cairo_lang_sierra::program::{BranchInfo, BranchTarget, Invocation, StatementIdx};
+use cairo_lang_sierra::program::{BranchInfo, BranchTarget, GenericArg, Invocation, StatementIdx};
 use cairo_lang_sierra_ap_change::ap_change_info::ApChangeInfo;
 use cairo_lang_sierra_gas::gas_info::GasInfo;
 use itertools::{zip_eq, Itertools};
 
-use super::{compile_invocation, CompiledInvocation, ProgramInfo};
+use super::{compile_invocation, CompiledInvocation, InvocationError, ProgramInfo};
 use crate::environment::gas_wallet::GasWallet;
 use crate::environment::Environment;
 use crate::metadata::Metadata;
@@ -45,42 +45,42 @@ macro_rules! cell_expr_operator {
 #[macro_export]
 macro_rules! ref_expr_extend {
     ($cells:ident) => {};
-    ($cells:ident, [$a:ident $($op:tt $offset:expr)?] $(, $tok:tt)*) => {
+    ($cells:ident, [$a:ident $($op:tt $offset:expr)?] $(, $($tok:tt)*)?) => {
         $cells.push(
             cairo_lang_casm::cell_expression::CellExpression::Deref(cairo_lang_casm::deref!([$a $($op $offset)?]))
         );
-        $crate::ref_expr_extend!($cells $(, $tok)*)
+        $crate::ref_expr_extend!($cells $(, $($tok)*)?)
     };
-    ($cells:ident, [$a:ident $($op:tt $offset:expr)?] $operator:tt $b:tt $(, $tok:tt)*) => {
+    ($cells:ident, [$a:ident $($op:tt $offset:expr)?] $operator:tt $b:tt $(, $($tok:tt)*)?) => {
         $cells.push(
             cairo_lang_casm::cell_expression::CellExpression::BinOp {
                 op: $crate::cell_expr_operator!($operator),
                 a: cairo_lang_casm::deref!([$a $($op $offset)?]),
                 b: cairo_lang_casm::deref_or_immediate!($b),
         });
-        $crate::ref_expr_extend!($cells $(, $tok)*)
+        $crate::ref_expr_extend!($cells $(, $($tok)*)?)
     };
-    ($cells:ident, [[$a:ident $($op:tt $offset:expr)?]] $(, $tok:tt)*) => {
+    ($cells:ident, [[$a:ident $($op:tt $offset:expr)?]] $(, $($tok:tt)*)?) => {
         $cells.push(
             cairo_lang_casm::cell_expression::CellExpression::DoubleDeref(cairo_lang_casm::deref!([$a $($op $offset)?]), 0)
         );
-        $crate::ref_expr_extend!($cells $(, $tok)*)
+        $crate::ref_expr_extend!($cells $(, $($tok)*)?)
     };
-    ($cells:ident, [[$a:ident $($op:tt $offset:expr)?] + $offset2:expr] $(, $tok:tt)*) => {
+    ($cells:ident, [[$a:ident $($op:tt $offset:expr)?] + $offset2:expr] $(, $($tok:tt)*)?) => {
         $cells.push(
             cairo_lang_casm::cell_expression::CellExpression::DoubleDeref(cairo_lang_casm::deref!([$a $($op $offset)?]), $offset2)
         );
-        $crate::ref_expr_extend!($cells $(, $tok)*)
+        $crate::ref_expr_extend!($cells $(, $($tok)*)?)
     };
-    ($cells:ident, $a:expr $(, $tok:tt)*) => {
-        cells.push(
+    ($cells:ident, $a:expr $(, $($tok:tt)*)?) => {
+        $cells.push(
             cairo_lang_casm::cell_expression::CellExpression::Immediate(num_bigint::BigInt::from($a))
         );
-        $crate::ref_expr_extend!($cells $(, $tok)*)
+        $crate::ref_expr_extend!($cells $(, $($tok)*)?)
     };
-    ($cells:ident, _ $(, $tok:tt)*) => {
-        cells.push(cairo_lang_casm::cell_expression::CellExpression::Padding);
-        $crate::ref_expr_extend!($cells $(, $tok)*)
+    ($cells:ident, _ $(, $($tok:tt)*)?) => {
+        $cells.push(cairo_lang_casm::cell_expression::CellExpression::Padding);
+        $crate::ref_expr_extend!($cells $(, $($tok)*)?)
     };
 }
 
@@ -230,6 +230,14 @@ impl std::fmt::Debug for ReducedCompiledInvocation {
 ///     k([0], [2],..., [n_k])
 /// }
 pub fn compile_libfunc(libfunc: &str, refs: Vec<ReferenceExpression>) -> ReducedCompiledInvocation {
+    try_compile_libfunc(libfunc, refs).expect("Failed to compile invocation.")
+}
+
+/// Same as [compile_libfunc], but returns the error instead of panicking if the compilation fails.
+pub fn try_compile_libfunc(
+    libfunc: &str,
+    refs: Vec<ReferenceExpression>,
+) -> Result<ReducedCompiledInvocation, InvocationError> {
     let long_id = cairo_lang_sierra::ConcreteLibfuncLongIdParser::new()
         .parse(libfunc.to_string().as_str())
         .unwrap();
@@ -239,6 +247,11 @@ pub fn compile_libfunc(libfunc: &str, refs: Vec<ReferenceExpression>) -> Reduced
             .unwrap();
 
     let mut type_sizes = HashMap::default();
+    for generic_arg in &long_id.generic_args {
+        if let GenericArg::Type(ty) = generic_arg {
+            type_sizes.insert(ty.clone(), context.try_get_type_info(ty.clone()).unwrap().size);
+        }
+    }
     for param in libfunc.param_signatures() {
         type_sizes
             .insert(param.ty.clone(), context.try_get_type_info(param.ty.clone()).unwrap().size);
@@ -268,31 +281,29 @@ pub fn compile_libfunc(libfunc: &str, refs: Vec<ReferenceExpression>) -> Reduced
         .collect();
 
     let environment = Environment::new(GasWallet::Disabled);
-    ReducedCompiledInvocation::new(
-        compile_invocation(
-            program_info,
-            &Invocation {
-                libfunc_id: "".into(),
-                args: (0..args.len()).map(VarId::from_usize).collect(),
-                branches: libfunc
-                    .branch_signatures()
-                    .iter()
-                    .enumerate()
-                    .map(|(i, branch)| BranchInfo {
-                        target: if libfunc.fallthrough() == Some(i) {
-                            BranchTarget::Fallthrough
-                        } else {
-                            BranchTarget::Statement(StatementIdx(i))
-                        },
-                        results: (0..branch.vars.len()).map(VarId::from_usize).collect(),
-                    })
-                    .collect(),
-            },
-            &libfunc,
-            StatementIdx(0),
-            &args,
-            environment,
-        )
-        .expect("Failed to compile invocation."),
+    compile_invocation(
+        program_info,
+        &Invocation {
+            libfunc_id: "".into(),
+            args: (0..args.len()).map(VarId::from_usize).collect(),
+            branches: libfunc
+                .branch_signatures()
+                .iter()
+                .enumerate()
+                .map(|(i, branch)| BranchInfo {
+                    target: if libfunc.fallthrough() == Some(i) {
+                        BranchTarget::Fallthrough
+                    } else {
+                        BranchTarget::Statement(StatementIdx(i))
+                    },
+                    results: (0..branch.vars.len()).map(VarId::from_usize).collect(),
+                })
+                .collect(),
+        },
+        &libfunc,
+        StatementIdx(0),
+        &args,
+        environment,
     )
+    .map(ReducedCompiledInvocation::new)
 }
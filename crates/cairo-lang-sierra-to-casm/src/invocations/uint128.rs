@@ -20,6 +20,7 @@ pub fn build(
             IntOperator::OverflowingSub => build_u128_overflowing_sub(builder),
         },
         Uint128Concrete::Divmod(_) => build_u128_divmod(builder),
+        Uint128Concrete::WrappingAdd(_) => build_u128_wrapping_add(builder),
         Uint128Concrete::WideMul(_) => build_u128_widemul(builder),
         Uint128Concrete::IsZero(_) => misc::build_is_zero(builder),
         Uint128Concrete::Const(libfunc) => super::uint::build_const(libfunc, builder),
@@ -71,6 +72,44 @@ fn build_u128_overflowing_add(
     ))
 }
 
+/// Handles a u128 wrapping add operation.
+fn build_u128_wrapping_add(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [range_check, a, b] = builder.try_get_single_cells()?;
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(0) range_check;
+        deref a;
+        deref b;
+    };
+    casm_build_extend! {casm_builder,
+            let orig_range_check = range_check;
+            tempvar no_overflow;
+            tempvar a_plus_b = a + b;
+            tempvar wrapping_a_plus_b;
+            const u128_limit = (BigInt::from(u128::MAX) + 1) as BigInt;
+            hint TestLessThan {lhs: a_plus_b, rhs: u128_limit} into {dst: no_overflow};
+            jump NoOverflow if no_overflow != 0;
+            // Overflow:
+            // Here we know that 2**128 <= a + b < 2 * (2**128 - 1).
+            assert wrapping_a_plus_b = a_plus_b - u128_limit;
+            jump Done;
+        NoOverflow:
+            assert wrapping_a_plus_b = a_plus_b;
+        Done:
+            assert wrapping_a_plus_b = *(range_check++);
+    };
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [("Fallthrough", &[&[range_check], &[wrapping_a_plus_b]], None)],
+        CostValidationInfo {
+            range_check_info: Some((orig_range_check, range_check)),
+            extra_costs: None,
+        },
+    ))
+}
+
 /// Handles a u128 overflowing sub operation.
 fn build_u128_overflowing_sub(
     builder: CompiledInvocationBuilder<'_>,
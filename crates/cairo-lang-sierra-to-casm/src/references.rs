@@ -12,6 +12,10 @@ use {cairo_lang_casm, cairo_lang_sierra};
 use crate::invocations::InvocationError;
 use crate::type_sizes::TypeSizeMap;
 
+#[cfg(test)]
+#[path = "references_test.rs"]
+mod test;
+
 #[derive(Error, Debug, Eq, PartialEq)]
 pub enum ReferencesError {
     #[error("Invalid function declaration.")]
@@ -64,6 +68,25 @@ impl ReferenceExpression {
         Self { cells: vec![cell_expr] }
     }
 
+    /// Builds a reference expression of `len` consecutive cells, starting at `base`.
+    /// `base` must be either a `Deref` or a `DoubleDeref` cell expression.
+    pub fn contiguous(base: CellExpression, len: i16) -> Self {
+        Self {
+            cells: (0..len)
+                .map(|i| match &base {
+                    CellExpression::Deref(cell) => CellExpression::Deref(CellRef {
+                        register: cell.register,
+                        offset: cell.offset + i,
+                    }),
+                    CellExpression::DoubleDeref(cell, offset) => {
+                        CellExpression::DoubleDeref(*cell, offset + i)
+                    }
+                    _ => panic!("A contiguous reference must start at a memory cell."),
+                })
+                .collect(),
+        }
+    }
+
     /// If returns the cells as an array of the requested size if the size is correct.
     pub fn try_unpack<const SIZE: usize>(
         &self,
@@ -109,13 +132,13 @@ pub fn build_function_arguments_refs(
             .insert(
                 param.id.clone(),
                 ReferenceValue {
-                    expression: ReferenceExpression {
-                        cells: ((offset - size + 1)..(offset + 1))
-                            .map(|i| {
-                                CellExpression::Deref(CellRef { register: Register::FP, offset: i })
-                            })
-                            .collect(),
-                    },
+                    expression: ReferenceExpression::contiguous(
+                        CellExpression::Deref(CellRef {
+                            register: Register::FP,
+                            offset: offset - size + 1,
+                        }),
+                        *size,
+                    ),
                     ty: param.ty.clone(),
                 },
             )
@@ -0,0 +1,39 @@
+use cairo_lang_casm::cell_expression::CellExpression;
+use cairo_lang_casm::operand::{CellRef, Register};
+use pretty_assertions::assert_eq;
+
+use crate::ref_expr;
+use crate::references::ReferenceExpression;
+
+#[test]
+fn test_contiguous_deref() {
+    assert_eq!(
+        ReferenceExpression::contiguous(
+            CellExpression::Deref(CellRef { register: Register::FP, offset: -5 }),
+            3
+        ),
+        ref_expr!([fp - 5], [fp - 4], [fp - 3])
+    );
+}
+
+#[test]
+fn test_contiguous_double_deref() {
+    assert_eq!(
+        ReferenceExpression::contiguous(
+            CellExpression::DoubleDeref(CellRef { register: Register::AP, offset: 1 }, 2),
+            2
+        ),
+        ref_expr!([[ap + 1] + 2], [[ap + 1] + 3])
+    );
+}
+
+#[test]
+fn test_contiguous_empty() {
+    assert_eq!(
+        ReferenceExpression::contiguous(
+            CellExpression::Deref(CellRef { register: Register::AP, offset: 0 }),
+            0
+        ),
+        ReferenceExpression { cells: vec![] }
+    );
+}
@@ -36,10 +36,12 @@ pub fn get_type_size_map(
             | CoreTypeConcrete::Pedersen(_)
             | CoreTypeConcrete::DictFeltTo(_)
             | CoreTypeConcrete::DictManager(_) => Some(1),
+            CoreTypeConcrete::Coupon(_) => Some(0),
             CoreTypeConcrete::Array(_)
             | CoreTypeConcrete::EcPoint(_)
             | CoreTypeConcrete::SquashedDictFeltTo(_) => Some(2),
-            CoreTypeConcrete::NonZero(InfoAndTypeConcreteType { ty, .. }) => {
+            CoreTypeConcrete::NonZero(InfoAndTypeConcreteType { ty, .. })
+            | CoreTypeConcrete::Snapshot(InfoAndTypeConcreteType { ty, .. }) => {
                 type_sizes.get(ty).cloned()
             }
             CoreTypeConcrete::EcState(_) => Some(3),
@@ -3,7 +3,7 @@ use super::array::{ArrayLibfunc, ArrayType};
 use super::bitwise::{BitwiseLibfunc, BitwiseType};
 use super::boolean::BoolLibfunc;
 use super::branch_align::BranchAlignLibfunc;
-use super::builtin_cost::{BuiltinCostLibfunc, BuiltinCostsType};
+use super::builtin_cost::{BuiltinCostLibfunc, BuiltinCostsType, CouponType};
 use super::debug::DebugLibfunc;
 use super::dict_felt_to::{DictFeltToLibfunc, DictFeltToType};
 use super::dict_manager::DictManagerType;
@@ -22,6 +22,7 @@ use super::modules::unconditional_jump::UnconditionalJumpLibfunc;
 use super::nullable::{NullableLibfunc, NullableType};
 use super::pedersen::{PedersenLibfunc, PedersenType};
 use super::range_check::RangeCheckType;
+use super::snapshot::{SnapshotTakeLibfunc, SnapshotType};
 use super::squashed_dict_felt_to::SquashedDictFeltToType;
 use super::starknet::{StarkNetLibfunc, StarkNetType};
 use super::structure::{StructLibfunc, StructType};
@@ -40,6 +41,7 @@ define_type_hierarchy! {
         Felt(FeltType),
         GasBuiltin(GasBuiltinType),
         BuiltinCosts(BuiltinCostsType),
+        Coupon(CouponType),
         Uint128(Uint128Type),
         Uint8(Uint8Type),
         Uint64(Uint64Type),
@@ -47,6 +49,7 @@ define_type_hierarchy! {
         Nullable(NullableType),
         RangeCheck(RangeCheckType),
         Uninitialized(UninitializedType),
+        Snapshot(SnapshotType),
         Enum(EnumType),
         Struct(StructType),
         DictFeltTo(DictFeltToType),
@@ -76,6 +79,7 @@ define_libfunc_hierarchy! {
         Uint8(Uint8Libfunc),
         Uint64(Uint64Libfunc),
         Mem(MemLibfunc),
+        SnapshotTake(SnapshotTakeLibfunc),
         Nullable(NullableLibfunc),
         UnwrapNonZero(UnwrapNonZeroLibfunc),
         UnconditionalJump(UnconditionalJumpLibfunc),
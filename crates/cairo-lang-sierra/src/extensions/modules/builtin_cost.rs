@@ -1,20 +1,27 @@
 use convert_case::Casing;
-use itertools::chain;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use super::gas::GasBuiltinType;
 use super::range_check::RangeCheckType;
 use crate::define_libfunc_hierarchy;
 use crate::extensions::lib_func::{
     BranchSignature, DeferredOutputKind, LibfuncSignature, OutputVarInfo, ParamSignature,
-    SierraApChange, SignatureSpecializationContext,
+    SierraApChange, SignatureBasedConcreteLibfunc, SignatureSpecializationContext,
+    SpecializationContext,
 };
+use crate::extensions::type_specialization_context::TypeSpecializationContext;
+use crate::extensions::types::{InfoOnlyConcreteType, TypeInfo};
 use crate::extensions::{
-    NamedType, NoGenericArgsGenericLibfunc, NoGenericArgsGenericType, OutputVarReferenceInfo,
-    SpecializationError,
+    NamedLibfunc, NamedType, NoGenericArgsGenericLibfunc, NoGenericArgsGenericType,
+    OutputVarReferenceInfo, SpecializationError,
 };
-use crate::ids::GenericTypeId;
+use crate::ids::{FunctionId, GenericTypeId};
+use crate::program::{Function, GenericArg};
 
 /// Represents different type of costs.
+///
+/// Serialized as its [CostTokenType::name], so the serialized form does not depend on the order of
+/// the variants.
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
 pub enum CostTokenType {
     /// A compile time known cost unit.
@@ -27,24 +34,48 @@ pub enum CostTokenType {
     EcOp,
 }
 impl CostTokenType {
-    pub fn iter()
-    -> std::iter::Chain<std::slice::Iter<'static, Self>, std::slice::Iter<'static, Self>> {
-        chain!(Self::iter_precost(), [CostTokenType::Const].iter())
+    /// All the token types, with their names, each at its [CostTokenType::index]. The token types
+    /// priced by the builtin costs array come first, in the order of their offsets in it, followed
+    /// by [CostTokenType::Const].
+    pub const ALL: [(CostTokenType, &'static str); 4] = [
+        (CostTokenType::Pedersen, "pedersen"),
+        (CostTokenType::Bitwise, "bitwise"),
+        (CostTokenType::EcOp, "ec_op"),
+        (CostTokenType::Const, "const"),
+    ];
+
+    /// Returns the index of the token type in [CostTokenType::ALL]. The match is exhaustive, so a
+    /// new variant cannot be added without a place in the table.
+    pub const fn index(&self) -> usize {
+        match self {
+            CostTokenType::Pedersen => 0,
+            CostTokenType::Bitwise => 1,
+            CostTokenType::EcOp => 2,
+            CostTokenType::Const => 3,
+        }
     }
 
-    pub fn iter_precost() -> std::slice::Iter<'static, Self> {
-        [CostTokenType::Pedersen, CostTokenType::Bitwise, CostTokenType::EcOp].iter()
+    pub fn iter() -> impl Iterator<Item = &'static Self> {
+        Self::ALL.iter().map(|(token_type, _)| token_type)
+    }
+
+    /// Returns the token types priced by the builtin costs array, i.e. all but
+    /// [CostTokenType::Const].
+    pub fn iter_precost() -> impl Iterator<Item = &'static Self> {
+        Self::iter().filter(|token_type| **token_type != CostTokenType::Const)
     }
 
     /// Returns the name of the token type, in snake_case.
     pub fn name(&self) -> String {
-        match self {
-            CostTokenType::Const => "const",
-            CostTokenType::Pedersen => "pedersen",
-            CostTokenType::Bitwise => "bitwise",
-            CostTokenType::EcOp => "ec_op",
-        }
-        .into()
+        Self::ALL[self.index()].1.into()
+    }
+
+    /// Returns the token type with the given [CostTokenType::name], if any.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .find(|(_, token_name)| *token_name == name)
+            .map(|(token_type, _)| *token_type)
     }
 
     pub fn camel_case_name(&self) -> String {
@@ -52,14 +83,23 @@ impl CostTokenType {
     }
 
     pub fn offset_in_builtin_costs(&self) -> i16 {
-        match self {
-            CostTokenType::Const => {
-                panic!("offset_in_builtin_costs is not supported for '{}'.", self.camel_case_name())
-            }
-            CostTokenType::Pedersen => 0,
-            CostTokenType::Bitwise => 1,
-            CostTokenType::EcOp => 2,
-        }
+        let Some(offset) = Self::iter_precost().position(|token_type| token_type == self) else {
+            panic!("offset_in_builtin_costs is not supported for '{}'.", self.camel_case_name())
+        };
+        offset as i16
+    }
+}
+
+impl Serialize for CostTokenType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.name())
+    }
+}
+impl<'de> Deserialize<'de> for CostTokenType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Self::from_name(&name)
+            .ok_or_else(|| serde::de::Error::custom(format!("Unknown cost token type `{name}`.")))
     }
 }
 
@@ -77,10 +117,47 @@ impl NoGenericArgsGenericType for BuiltinCostsType {
     const SIZE: i16 = 1;
 }
 
+/// Represents a prepaid call of the user function the type is specialized with.
+/// Bought using [CouponBuyLibfunc] and refunded using [CouponRefundLibfunc].
+#[derive(Default)]
+pub struct CouponType {}
+impl NamedType for CouponType {
+    type Concrete = InfoOnlyConcreteType;
+    const ID: GenericTypeId = GenericTypeId::new_inline("Coupon");
+
+    fn specialize(
+        &self,
+        _context: &dyn TypeSpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<Self::Concrete, SpecializationError> {
+        args_as_single_user_func(args)?;
+        Ok(InfoOnlyConcreteType {
+            info: TypeInfo {
+                long_id: Self::concrete_type_long_id(args),
+                storable: true,
+                droppable: true,
+                duplicatable: false,
+                size: 0,
+            },
+        })
+    }
+}
+
+/// Returns the function id of the generic args of a coupon, if it is the only arg.
+fn args_as_single_user_func(args: &[GenericArg]) -> Result<&FunctionId, SpecializationError> {
+    match args {
+        [GenericArg::UserFunc(function_id)] => Ok(function_id),
+        [_] => Err(SpecializationError::UnsupportedGenericArg),
+        _ => Err(SpecializationError::WrongNumberOfGenericArgs),
+    }
+}
+
 define_libfunc_hierarchy! {
     pub enum BuiltinCostLibfunc {
         BuiltinGetGas(BuiltinCostGetGasLibfunc),
         GetBuiltinCosts(BuiltinCostGetBuiltinCostsLibfunc),
+        CouponBuy(CouponBuyLibfunc),
+        CouponRefund(CouponRefundLibfunc),
     }, BuiltinCostConcreteLibfunc
 }
 
@@ -187,3 +264,132 @@ impl NoGenericArgsGenericLibfunc for BuiltinCostGetBuiltinCostsLibfunc {
         ))
     }
 }
+
+/// Libfunc for buying a coupon for a call of a user function, deducting the full cost of the
+/// function from the gas builtin up front. Fails if there is not enough gas.
+#[derive(Default)]
+pub struct CouponBuyLibfunc {}
+impl NamedLibfunc for CouponBuyLibfunc {
+    type Concrete = CouponConcreteLibfunc;
+    const STR_ID: &'static str = "coupon_buy";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        args_as_single_user_func(args)?;
+        let gas_builtin_type = context.get_concrete_type(GasBuiltinType::id(), &[])?;
+        let range_check_type = context.get_concrete_type(RangeCheckType::id(), &[])?;
+        let coupon_type = context.get_concrete_type(CouponType::id(), args)?;
+        Ok(LibfuncSignature {
+            param_signatures: vec![
+                ParamSignature {
+                    ty: range_check_type.clone(),
+                    allow_deferred: false,
+                    allow_add_const: true,
+                    allow_const: false,
+                },
+                ParamSignature::new(gas_builtin_type.clone()),
+            ],
+            branch_signatures: vec![
+                // Success:
+                BranchSignature {
+                    vars: vec![
+                        OutputVarInfo {
+                            ty: range_check_type.clone(),
+                            ref_info: OutputVarReferenceInfo::Deferred(
+                                DeferredOutputKind::AddConst { param_idx: 0 },
+                            ),
+                        },
+                        OutputVarInfo {
+                            ty: gas_builtin_type.clone(),
+                            ref_info: OutputVarReferenceInfo::NewTempVar { idx: Some(0) },
+                        },
+                        OutputVarInfo {
+                            ty: coupon_type,
+                            ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::Generic),
+                        },
+                    ],
+                    ap_change: SierraApChange::Known { new_vars_only: false },
+                },
+                // Failure:
+                BranchSignature {
+                    vars: vec![
+                        OutputVarInfo {
+                            ty: range_check_type,
+                            ref_info: OutputVarReferenceInfo::Deferred(
+                                DeferredOutputKind::AddConst { param_idx: 0 },
+                            ),
+                        },
+                        OutputVarInfo {
+                            ty: gas_builtin_type,
+                            ref_info: OutputVarReferenceInfo::SameAsParam { param_idx: 1 },
+                        },
+                    ],
+                    ap_change: SierraApChange::Known { new_vars_only: false },
+                },
+            ],
+            fallthrough: Some(0),
+        })
+    }
+
+    fn specialize(
+        &self,
+        context: &dyn SpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<Self::Concrete, SpecializationError> {
+        Ok(CouponConcreteLibfunc {
+            function: context.get_function(args_as_single_user_func(args)?)?,
+            signature: self.specialize_signature(context.upcast(), args)?,
+        })
+    }
+}
+
+/// Libfunc for refunding an unused coupon, returning the cost of the function to the gas builtin.
+#[derive(Default)]
+pub struct CouponRefundLibfunc {}
+impl NamedLibfunc for CouponRefundLibfunc {
+    type Concrete = CouponConcreteLibfunc;
+    const STR_ID: &'static str = "coupon_refund";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        args_as_single_user_func(args)?;
+        let gas_builtin_type = context.get_concrete_type(GasBuiltinType::id(), &[])?;
+        let coupon_type = context.get_concrete_type(CouponType::id(), args)?;
+        Ok(LibfuncSignature::new_non_branch(
+            vec![gas_builtin_type.clone(), coupon_type],
+            vec![OutputVarInfo {
+                ty: gas_builtin_type,
+                ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::Generic),
+            }],
+            SierraApChange::Known { new_vars_only: true },
+        ))
+    }
+
+    fn specialize(
+        &self,
+        context: &dyn SpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<Self::Concrete, SpecializationError> {
+        Ok(CouponConcreteLibfunc {
+            function: context.get_function(args_as_single_user_func(args)?)?,
+            signature: self.specialize_signature(context.upcast(), args)?,
+        })
+    }
+}
+
+/// A concrete coupon libfunc, holding the function the coupon is for.
+pub struct CouponConcreteLibfunc {
+    pub function: Function,
+    pub signature: LibfuncSignature,
+}
+impl SignatureBasedConcreteLibfunc for CouponConcreteLibfunc {
+    fn signature(&self) -> &LibfuncSignature {
+        &self.signature
+    }
+}
@@ -29,6 +29,7 @@ pub mod non_zero;
 pub mod nullable;
 pub mod pedersen;
 pub mod range_check;
+pub mod snapshot;
 pub mod squashed_dict_felt_to;
 pub mod starknet;
 pub mod structure;
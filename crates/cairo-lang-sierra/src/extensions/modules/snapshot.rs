@@ -0,0 +1,57 @@
+use crate::extensions::lib_func::{
+    LibfuncSignature, OutputVarInfo, SierraApChange, SignatureAndTypeGenericLibfunc,
+    SignatureSpecializationContext, WrapSignatureAndTypeGenericLibfunc,
+};
+use crate::extensions::types::{
+    GenericTypeArgGenericType, GenericTypeArgGenericTypeWrapper, TypeInfo,
+};
+use crate::extensions::{NamedType, OutputVarReferenceInfo, SpecializationError};
+use crate::ids::{ConcreteTypeId, GenericTypeId};
+
+/// Type for an immutable snapshot of a value.
+/// A snapshot shares the representation of the wrapped value, but may always be duplicated and
+/// dropped.
+#[derive(Default)]
+pub struct SnapshotTypeWrapped {}
+impl GenericTypeArgGenericType for SnapshotTypeWrapped {
+    const ID: GenericTypeId = GenericTypeId::new_inline("Snapshot");
+
+    fn calc_info(
+        &self,
+        long_id: crate::program::ConcreteTypeLongId,
+        wrapped_info: TypeInfo,
+    ) -> Result<TypeInfo, SpecializationError> {
+        Ok(TypeInfo { long_id, duplicatable: true, droppable: true, ..wrapped_info })
+    }
+}
+pub type SnapshotType = GenericTypeArgGenericTypeWrapper<SnapshotTypeWrapped>;
+
+/// Libfunc for taking a snapshot `Snapshot<T>` of an object of type T, returning the original
+/// object as well.
+#[derive(Default)]
+pub struct SnapshotTakeLibfuncWrapped {}
+impl SignatureAndTypeGenericLibfunc for SnapshotTakeLibfuncWrapped {
+    const STR_ID: &'static str = "snapshot_take";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+        ty: ConcreteTypeId,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        Ok(LibfuncSignature::new_non_branch(
+            vec![ty.clone()],
+            vec![
+                OutputVarInfo {
+                    ty: ty.clone(),
+                    ref_info: OutputVarReferenceInfo::SameAsParam { param_idx: 0 },
+                },
+                OutputVarInfo {
+                    ty: context.get_wrapped_concrete_type(SnapshotType::id(), ty)?,
+                    ref_info: OutputVarReferenceInfo::SameAsParam { param_idx: 0 },
+                },
+            ],
+            SierraApChange::Known { new_vars_only: true },
+        ))
+    }
+}
+pub type SnapshotTakeLibfunc = WrapSignatureAndTypeGenericLibfunc<SnapshotTakeLibfuncWrapped>;
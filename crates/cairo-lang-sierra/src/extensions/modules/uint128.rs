@@ -25,6 +25,7 @@ define_libfunc_hierarchy! {
     pub enum Uint128Libfunc {
         Operation(UintOperationLibfunc<Uint128Traits>),
         Divmod(UintDivmodLibfunc<Uint128Traits>),
+        WrappingAdd(Uint128WrappingAddLibfunc),
         WideMul(Uint128WideMulLibfunc),
         LessThan(UintLessThanLibfunc<Uint128Traits>),
         Equal(UintEqualLibfunc<Uint128Traits>),
@@ -150,6 +151,43 @@ impl GenericLibfunc for Uint128OperationLibfunc {
     }
 }
 
+/// Libfunc for u128 wrapping add - returns the sum modulo 2**128, discarding the overflow.
+#[derive(Default)]
+pub struct Uint128WrappingAddLibfunc {}
+impl NoGenericArgsGenericLibfunc for Uint128WrappingAddLibfunc {
+    const STR_ID: &'static str = "u128_wrapping_add";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let ty = context.get_concrete_type(Uint128Type::id(), &[])?;
+        let range_check_type = context.get_concrete_type(RangeCheckType::id(), &[])?;
+        Ok(LibfuncSignature::new_non_branch_ex(
+            vec![
+                ParamSignature {
+                    ty: range_check_type.clone(),
+                    allow_deferred: false,
+                    allow_add_const: true,
+                    allow_const: false,
+                },
+                ParamSignature::new(ty.clone()),
+                ParamSignature::new(ty.clone()),
+            ],
+            vec![
+                OutputVarInfo {
+                    ty: range_check_type,
+                    ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::AddConst {
+                        param_idx: 0,
+                    }),
+                },
+                OutputVarInfo { ty, ref_info: OutputVarReferenceInfo::NewTempVar { idx: Some(0) } },
+            ],
+            SierraApChange::Known { new_vars_only: false },
+        ))
+    }
+}
+
 /// Libfunc for u128 wide mul.
 #[derive(Default)]
 pub struct Uint128WideMulLibfunc {}
@@ -2,15 +2,19 @@ use bimap::BiMap;
 use num_bigint::BigInt;
 use test_case::test_case;
 
+use super::builtin_cost::{BuiltinCostGetGasLibfunc, CostTokenType};
 use super::core::{CoreLibfunc, CoreType};
-use super::lib_func::{SierraApChange, SignatureSpecializationContext, SpecializationContext};
+use super::lib_func::{
+    DeferredOutputKind, OutputVarReferenceInfo, SierraApChange, SignatureSpecializationContext,
+    SpecializationContext,
+};
 use super::types::TypeInfo;
 use super::SpecializationError::{
     self, IndexOutOfRange, MissingFunction, UnsupportedGenericArg, UnsupportedId,
     WrongNumberOfGenericArgs,
 };
 use crate::extensions::type_specialization_context::TypeSpecializationContext;
-use crate::extensions::{GenericLibfunc, GenericType};
+use crate::extensions::{ConcreteLibfunc, GenericLibfunc, GenericType};
 use crate::ids::{ConcreteTypeId, FunctionId, GenericTypeId};
 use crate::program::{ConcreteTypeLongId, Function, FunctionSignature, GenericArg, StatementIdx};
 use crate::test_utils::build_bijective_mapping;
@@ -72,6 +76,14 @@ impl TypeSpecializationContext for MockSpecializationContext {
                 duplicatable: false,
                 size: 0,
             })
+        } else if id == "CouponRegisteredFunction".into() {
+            Some(TypeInfo {
+                long_id: self.mapping.get_by_left(&id)?.clone(),
+                storable: true,
+                droppable: true,
+                duplicatable: false,
+                size: 0,
+            })
         } else if id == "GasBuiltin".into() || id == "System".into() {
             Some(TypeInfo {
                 long_id: self.mapping.get_by_left(&id)?.clone(),
@@ -134,8 +146,10 @@ impl SpecializationContext for MockSpecializationContext {
 #[test_case("RangeCheck", vec![type_arg("T")] => Err(WrongNumberOfGenericArgs); "RangeCheck<T>")]
 #[test_case("felt", vec![] => Ok(()); "felt")]
 #[test_case("felt", vec![type_arg("T")] => Err(WrongNumberOfGenericArgs); "felt<T>")]
+#[test_case("felt", vec![value_arg(1)] => Err(WrongNumberOfGenericArgs); "felt<1>")]
 #[test_case("u128", vec![] => Ok(()); "u128")]
 #[test_case("u128", vec![type_arg("T")] => Err(WrongNumberOfGenericArgs); "u128<T>")]
+#[test_case("Array", vec![type_arg("felt")] => Ok(()); "Array<felt>")]
 #[test_case("Array", vec![type_arg("u128")] => Ok(()); "Array<u128>")]
 #[test_case("Array", vec![] => Err(WrongNumberOfGenericArgs); "Array")]
 #[test_case("Array", vec![value_arg(5)] => Err(UnsupportedGenericArg); "Array<5>")]
@@ -174,6 +188,10 @@ impl SpecializationContext for MockSpecializationContext {
             "Struct<u128, felt>")]
 #[test_case("System", vec![] => Ok(()); "System")]
 #[test_case("StorageBaseAddress", vec![] => Ok(()); "StorageBaseAddress")]
+#[test_case("Coupon", vec![GenericArg::UserFunc("RegisteredFunction".into())] => Ok(());
+            "Coupon<&RegisteredFunction>")]
+#[test_case("Coupon", vec![] => Err(WrongNumberOfGenericArgs); "Coupon")]
+#[test_case("Coupon", vec![type_arg("felt")] => Err(UnsupportedGenericArg); "Coupon<felt>")]
 fn find_type_specialization(
     id: &str,
     generic_args: Vec<GenericArg>,
@@ -197,10 +215,24 @@ fn find_type_specialization(
 #[test_case("array_append", vec![type_arg("u128")] => Ok(()); "array_append<u128>")]
 #[test_case("array_get", vec![] => Err(WrongNumberOfGenericArgs); "array_get")]
 #[test_case("array_get", vec![type_arg("u128")] => Ok(()); "array_get<u128>")]
+#[test_case("array_pop_front", vec![] => Err(WrongNumberOfGenericArgs); "array_pop_front")]
+#[test_case("array_pop_front", vec![type_arg("u128")] => Ok(()); "array_pop_front<u128>")]
 #[test_case("array_len", vec![] => Err(WrongNumberOfGenericArgs); "array_len")]
 #[test_case("array_len", vec![type_arg("u128")] => Ok(()); "array_len<u128>")]
 #[test_case("get_gas", vec![value_arg(0)] => Err(WrongNumberOfGenericArgs); "get_gas<0>")]
 #[test_case("get_gas", vec![] => Ok(()); "get_gas")]
+#[test_case("coupon_buy", vec![GenericArg::UserFunc("RegisteredFunction".into())] => Ok(());
+            "coupon_buy<&RegisteredFunction>")]
+#[test_case("coupon_buy", vec![GenericArg::UserFunc("UnregisteredFunction".into())]
+            => Err(MissingFunction("UnregisteredFunction".into()));
+            "coupon_buy<&UnregisteredFunction>")]
+#[test_case("coupon_buy", vec![] => Err(WrongNumberOfGenericArgs); "coupon_buy")]
+#[test_case("coupon_refund", vec![GenericArg::UserFunc("RegisteredFunction".into())] => Ok(());
+            "coupon_refund<&RegisteredFunction>")]
+#[test_case("coupon_refund", vec![GenericArg::UserFunc("UnregisteredFunction".into())]
+            => Err(MissingFunction("UnregisteredFunction".into()));
+            "coupon_refund<&UnregisteredFunction>")]
+#[test_case("coupon_refund", vec![] => Err(WrongNumberOfGenericArgs); "coupon_refund")]
 #[test_case("refund_gas", vec![value_arg(0)] => Err(WrongNumberOfGenericArgs); "refund_gas<0>")]
 #[test_case("refund_gas", vec![] => Ok(()); "refund_gas")]
 #[test_case("felt_add", vec![] => Ok(()); "felt_add")]
@@ -212,6 +244,7 @@ fn find_type_specialization(
             => Err(WrongNumberOfGenericArgs); "felt_is_zero<int>")]
 #[test_case("u128_overflowing_add", vec![] => Ok(()); "u128_overflowing_add")]
 #[test_case("u128_overflowing_sub", vec![] => Ok(()); "u128_overflowing_sub")]
+#[test_case("u128_wrapping_add", vec![] => Ok(()); "u128_wrapping_add")]
 #[test_case("u128_safe_divmod", vec![] => Ok(()); "u128_safe_divmod")]
 #[test_case("u128_const", vec![value_arg(8)] => Ok(()); "u128_const<8>")]
 #[test_case("u128_const", vec![] => Err(UnsupportedGenericArg); "u128_const")]
@@ -295,3 +328,171 @@ fn find_libfunc_specialization(
         .specialize(&MockSpecializationContext::new(), &generic_args)
         .map(|_| ())
 }
+
+#[test]
+fn array_pop_front_branch_signatures() {
+    let libfunc = CoreLibfunc::by_id(&"array_pop_front".into())
+        .unwrap()
+        .specialize(&MockSpecializationContext::new(), &[type_arg("u128")])
+        .unwrap();
+    let branch_output_types: Vec<Vec<ConcreteTypeId>> = libfunc
+        .branch_signatures()
+        .iter()
+        .map(|branch| branch.vars.iter().map(|var| var.ty.clone()).collect())
+        .collect();
+    // The non-empty branch outputs the rest of the array and the popped value, and the empty
+    // branch outputs only the array.
+    assert_eq!(
+        branch_output_types,
+        vec![vec!["ArrayU128".into(), "u128".into()], vec!["ArrayU128".into()]]
+    );
+    assert_eq!(libfunc.fallthrough(), Some(0));
+}
+
+#[test]
+fn u128_wrapping_add_signature() {
+    let libfunc = CoreLibfunc::by_id(&"u128_wrapping_add".into())
+        .unwrap()
+        .specialize(&MockSpecializationContext::new(), &[])
+        .unwrap();
+    let branch_output_types: Vec<Vec<ConcreteTypeId>> = libfunc
+        .branch_signatures()
+        .iter()
+        .map(|branch| branch.vars.iter().map(|var| var.ty.clone()).collect())
+        .collect();
+    // A single branch outputting the range check and the wrapped sum.
+    assert_eq!(branch_output_types, vec![vec!["RangeCheck".into(), "u128".into()]]);
+    assert_eq!(libfunc.fallthrough(), Some(0));
+}
+
+#[test]
+fn into_nullable_signature() {
+    let libfunc = CoreLibfunc::by_id(&"into_nullable".into())
+        .unwrap()
+        .specialize(&MockSpecializationContext::new(), &[type_arg("felt")])
+        .unwrap();
+    assert_eq!(
+        libfunc.param_signatures().iter().map(|param| param.ty.clone()).collect::<Vec<_>>(),
+        vec![ConcreteTypeId::from("BoxFelt")]
+    );
+    let [branch] = libfunc.branch_signatures() else { panic!("Expected a single branch.") };
+    assert_eq!(
+        branch.vars.iter().map(|var| var.ty.clone()).collect::<Vec<_>>(),
+        vec![ConcreteTypeId::from("NullableFelt")]
+    );
+    // The nullable is the same pointer as the box.
+    assert!(matches!(
+        branch.vars[0].ref_info,
+        OutputVarReferenceInfo::SameAsParam { param_idx: 0 }
+    ));
+}
+
+#[test]
+fn coupon_buy_signature() {
+    let libfunc = CoreLibfunc::by_id(&"coupon_buy".into())
+        .unwrap()
+        .specialize(
+            &MockSpecializationContext::new(),
+            &[GenericArg::UserFunc("RegisteredFunction".into())],
+        )
+        .unwrap();
+    let branch_output_types: Vec<Vec<ConcreteTypeId>> = libfunc
+        .branch_signatures()
+        .iter()
+        .map(|branch| branch.vars.iter().map(|var| var.ty.clone()).collect())
+        .collect();
+    assert_eq!(
+        branch_output_types,
+        vec![
+            vec!["RangeCheck".into(), "GasBuiltin".into(), "CouponRegisteredFunction".into()],
+            vec!["RangeCheck".into(), "GasBuiltin".into()],
+        ]
+    );
+    assert_eq!(
+        libfunc.param_signatures().iter().map(|param| param.ty.clone()).collect::<Vec<_>>(),
+        vec![ConcreteTypeId::from("RangeCheck"), "GasBuiltin".into()]
+    );
+    // The gas builtin is consumed on success and returned untouched on failure.
+    assert!(matches!(
+        libfunc.branch_signatures()[0].vars[1].ref_info,
+        OutputVarReferenceInfo::NewTempVar { idx: Some(0) }
+    ));
+    assert!(matches!(
+        libfunc.branch_signatures()[1].vars[1].ref_info,
+        OutputVarReferenceInfo::SameAsParam { param_idx: 1 }
+    ));
+    assert_eq!(libfunc.fallthrough(), Some(0));
+}
+
+#[test]
+fn get_builtin_costs_signature() {
+    let libfunc = CoreLibfunc::by_id(&"get_builtin_costs".into())
+        .unwrap()
+        .specialize(&MockSpecializationContext::new(), &[])
+        .unwrap();
+    assert!(libfunc.param_signatures().is_empty());
+    let [branch] = libfunc.branch_signatures() else { panic!("Expected a single branch.") };
+    assert_eq!(
+        branch.vars.iter().map(|var| var.ty.clone()).collect::<Vec<_>>(),
+        vec![ConcreteTypeId::from("BuiltinCosts")]
+    );
+    assert!(matches!(
+        branch.vars[0].ref_info,
+        OutputVarReferenceInfo::Deferred(DeferredOutputKind::Generic)
+    ));
+    assert_eq!(libfunc.fallthrough(), Some(0));
+}
+
+#[test]
+fn cost_token_type_serde_round_trip() {
+    for token_type in CostTokenType::iter() {
+        let serialized = serde_json::to_string(token_type).unwrap();
+        assert_eq!(serialized, format!("\"{}\"", token_type.name()));
+        assert_eq!(CostTokenType::from_name(&token_type.name()), Some(*token_type));
+        assert_eq!(serde_json::from_str::<CostTokenType>(&serialized).unwrap(), *token_type);
+    }
+    assert_eq!(CostTokenType::from_name("unknown"), None);
+    assert!(serde_json::from_str::<CostTokenType>("\"unknown\"").is_err());
+}
+
+#[test_case(&[], 0; "no tokens")]
+#[test_case(&[(CostTokenType::Bitwise, 1)], 2; "single bitwise")]
+#[test_case(&[(CostTokenType::Bitwise, 4)], 3; "multiple bitwise")]
+#[test_case(&[(CostTokenType::Pedersen, 1), (CostTokenType::Bitwise, 2)], 5; "pedersen and bitwise")]
+#[test_case(&[(CostTokenType::Const, 7)], 0; "const only")]
+fn get_gas_all_cost_computation_steps(token_usages: &[(CostTokenType, usize)], expected: usize) {
+    assert_eq!(
+        BuiltinCostGetGasLibfunc::cost_computation_steps(|token_type| {
+            token_usages
+                .iter()
+                .find(|(usage_token_type, _)| *usage_token_type == token_type)
+                .map_or(0, |(_, count)| *count)
+        }),
+        expected
+    );
+}
+
+#[test_case(CostTokenType::Pedersen, "pedersen", 0)]
+#[test_case(CostTokenType::Bitwise, "bitwise", 1)]
+#[test_case(CostTokenType::EcOp, "ec_op", 2)]
+fn precost_token_type_name_and_offset(token_type: CostTokenType, name: &str, offset: i16) {
+    assert_eq!(token_type.name(), name);
+    assert_eq!(CostTokenType::from_name(name), Some(token_type));
+    assert_eq!(token_type.offset_in_builtin_costs(), offset);
+    assert!(CostTokenType::iter_precost().any(|precost| *precost == token_type));
+}
+
+#[test]
+fn cost_token_type_table_is_indexed_by_variant() {
+    for (index, (token_type, _)) in CostTokenType::ALL.iter().enumerate() {
+        assert_eq!(token_type.index(), index);
+        assert_eq!(CostTokenType::ALL[token_type.index()].0, *token_type);
+    }
+}
+
+#[test]
+fn cost_token_type_names_are_distinct() {
+    let names: Vec<_> = CostTokenType::iter().map(|token_type| token_type.name()).collect();
+    assert!(names.iter().all(|name| !name.is_empty()));
+    assert_eq!(names.iter().collect::<std::collections::HashSet<_>>().len(), names.len());
+}
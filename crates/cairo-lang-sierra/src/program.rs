@@ -1,3 +1,9 @@
+#[cfg(test)]
+#[path = "program_test.rs"]
+mod test;
+
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
+use itertools::zip_eq;
 use num_bigint::BigInt;
 
 use crate::ids::{
@@ -21,6 +27,130 @@ impl Program {
     pub fn get_statement(&self, id: &StatementIdx) -> Option<&Statement> {
         self.statements.get(id.0)
     }
+
+    /// Returns the statements that cannot be reached from the entry point of any function, in
+    /// order. A statement is reached by a branch of an invocation; a return ends the flow.
+    pub fn unreachable_statements(&self) -> Vec<StatementIdx> {
+        let mut visited = vec![false; self.statements.len()];
+        let mut stack: Vec<StatementIdx> = self.funcs.iter().map(|f| f.entry_point).collect();
+        while let Some(idx) = stack.pop() {
+            match visited.get_mut(idx.0) {
+                Some(visited) if !*visited => *visited = true,
+                _ => continue,
+            }
+            stack.extend(self.successors(idx));
+        }
+        visited
+            .into_iter()
+            .enumerate()
+            .filter(|(_, visited)| !visited)
+            .map(|(idx, _)| StatementIdx(idx))
+            .collect()
+    }
+
+    /// Returns the branches from a statement back to a statement on the flow leading to it, as
+    /// found by a depth-first search from the function entry points, as `(from, to)` pairs.
+    pub fn back_edges(&self) -> Vec<(StatementIdx, StatementIdx)> {
+        self.depth_first_search().1
+    }
+
+    /// Returns whether the flow of the program has a loop, i.e. any back edge.
+    pub fn has_cycle(&self) -> bool {
+        !self.back_edges().is_empty()
+    }
+
+    /// Returns the variables live before each statement, i.e. the ones that may still be used by
+    /// it or by the statements the flow continues to. Invocation args and returned variables are
+    /// used, and branch results are defined; the variables live after an invocation are the union
+    /// of the ones live at its branch targets. Statements that cannot be reached from the entry
+    /// point of any function have no live variables.
+    pub fn live_vars(&self) -> Vec<OrderedHashSet<VarId>> {
+        let (postorder, back_edges) = self.depth_first_search();
+        let mut live_vars = vec![OrderedHashSet::<VarId>::default(); self.statements.len()];
+        // In postorder, a statement is visited after all of its successors except for the targets
+        // of back edges, so a fixpoint is only iterated to when there are back edges. The sets only
+        // grow between iterations, so comparing their sizes detects a change.
+        loop {
+            let mut changed = false;
+            for idx in &postorder {
+                let mut live = OrderedHashSet::<VarId>::default();
+                match &self.statements[idx.0] {
+                    Statement::Invocation(invocation) => {
+                        live.extend(invocation.args.iter().cloned());
+                        for (branch, target) in zip_eq(&invocation.branches, self.successors(*idx))
+                        {
+                            let target_live =
+                                live_vars.get(target.0).into_iter().flat_map(|live| live.iter());
+                            live.extend(
+                                target_live.filter(|var| !branch.results.contains(var)).cloned(),
+                            );
+                        }
+                    }
+                    Statement::Return(vars) => live.extend(vars.iter().cloned()),
+                }
+                if live.len() != live_vars[idx.0].len() {
+                    live_vars[idx.0] = live;
+                    changed = true;
+                }
+            }
+            if !changed || back_edges.is_empty() {
+                break;
+            }
+        }
+        live_vars
+    }
+
+    /// Runs a depth-first search from the function entry points, returning the visited
+    /// statements in postorder and the back edges - the branches from a statement back to a
+    /// statement on the flow leading to it - as `(from, to)` pairs.
+    fn depth_first_search(&self) -> (Vec<StatementIdx>, Vec<(StatementIdx, StatementIdx)>) {
+        #[derive(Clone, Copy, PartialEq)]
+        enum VisitState {
+            New,
+            OnPath,
+            Done,
+        }
+        let mut states = vec![VisitState::New; self.statements.len()];
+        let mut postorder = vec![];
+        let mut back_edges = vec![];
+        for func in &self.funcs {
+            if states.get(func.entry_point.0) != Some(&VisitState::New) {
+                continue;
+            }
+            states[func.entry_point.0] = VisitState::OnPath;
+            // The statements on the current path, with the number of their visited successors.
+            let mut path = vec![(func.entry_point, 0)];
+            while let Some((idx, visited_successors)) = path.last_mut() {
+                let idx = *idx;
+                let Some(next) = self.successors(idx).get(*visited_successors).copied() else {
+                    states[idx.0] = VisitState::Done;
+                    postorder.push(idx);
+                    path.pop();
+                    continue;
+                };
+                *visited_successors += 1;
+                match states.get(next.0) {
+                    Some(VisitState::New) => {
+                        states[next.0] = VisitState::OnPath;
+                        path.push((next, 0));
+                    }
+                    Some(VisitState::OnPath) => back_edges.push((idx, next)),
+                    Some(VisitState::Done) | None => {}
+                }
+            }
+        }
+        (postorder, back_edges)
+    }
+
+    /// Returns the statements the flow may continue to after the given statement.
+    fn successors(&self, idx: StatementIdx) -> Vec<StatementIdx> {
+        match &self.statements[idx.0] {
+            Statement::Invocation(invocation) => {
+                invocation.branches.iter().map(|branch| idx.next(&branch.target)).collect()
+            }
+            Statement::Return(_) => vec![],
+        }
+    }
 }
 
 /// Declaration of a concrete type.
@@ -48,6 +178,9 @@ pub struct LibfuncDeclaration {
     pub long_id: ConcreteLibfuncLongId,
 }
 
+// Note: Generic args are never substituted here (e.g. `store_temp<T>` into `store_temp<felt>`), as
+// the generated Sierra is already monomorphic: generic params are substituted in the semantic
+// model, by `GenericSubstitution` and `substitute_ty` in `cairo_lang_semantic::types`.
 /// A concrete library function (the generic parent function and the generic arguments).
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub struct ConcreteLibfuncLongId {
@@ -2,7 +2,8 @@ use indoc::indoc;
 use test_log::test;
 
 use crate::extensions::core::{CoreLibfunc, CoreType};
-use crate::program::{ConcreteTypeLongId, TypeDeclaration};
+use crate::extensions::error::{ExtensionError, SpecializationError};
+use crate::program::{ConcreteTypeLongId, GenericArg, TypeDeclaration};
 use crate::program_registry::{ProgramRegistry, ProgramRegistryError};
 use crate::ProgramParser;
 
@@ -95,3 +96,52 @@ fn libfunc_id_double_declaration() {
         Err(Box::new(ProgramRegistryError::LibfuncConcreteIdAlreadyExists("used_id".into())))
     );
 }
+
+#[test]
+fn unknown_libfunc_declaration() {
+    assert_eq!(
+        ProgramRegistry::<CoreType, CoreLibfunc>::new(
+            &ProgramParser::new()
+                .parse(indoc! {"
+                    type u128 = u128;
+                    libfunc unknown_u128 = unknown<u128>;
+                "})
+                .unwrap()
+        )
+        .map(|_| ()),
+        Err(Box::new(ProgramRegistryError::LibfuncSpecialization {
+            concrete_id: "unknown_u128".into(),
+            error: ExtensionError::LibfuncSpecialization {
+                libfunc_id: "unknown".into(),
+                generic_args: vec![GenericArg::Type("u128".into())],
+                error: SpecializationError::UnsupportedId,
+            },
+        }))
+    );
+}
+
+#[test]
+fn libfunc_wrong_number_of_generic_args() {
+    assert_eq!(
+        ProgramRegistry::<CoreType, CoreLibfunc>::new(
+            &ProgramParser::new()
+                .parse(indoc! {"
+                    type u128 = u128;
+                    libfunc rename_u128 = rename<u128, u128>;
+                "})
+                .unwrap()
+        )
+        .map(|_| ()),
+        Err(Box::new(ProgramRegistryError::LibfuncSpecialization {
+            concrete_id: "rename_u128".into(),
+            error: ExtensionError::LibfuncSpecialization {
+                libfunc_id: "rename".into(),
+                generic_args: vec![
+                    GenericArg::Type("u128".into()),
+                    GenericArg::Type("u128".into())
+                ],
+                error: SpecializationError::WrongNumberOfGenericArgs,
+            },
+        }))
+    );
+}
@@ -0,0 +1,98 @@
+use indoc::indoc;
+use itertools::Itertools;
+
+use super::{Program, StatementIdx};
+use crate::ProgramParser;
+
+#[test]
+fn test_unreachable_statements() {
+    let program = ProgramParser::new()
+        .parse(indoc! {"
+            type felt = felt;
+            libfunc felt_is_zero = felt_is_zero;
+            libfunc jump = jump;
+            felt_is_zero(a) { fallthrough() 3(b) };
+            return();
+            return();
+            jump() { 1() };
+            return();
+            return();
+            Func@0(a: felt) -> ();
+            Other@4() -> ();
+        "})
+        .unwrap();
+    // Statement 2 is skipped by both branches and 5 comes after a return. Statement 4 is only
+    // reached as the entry point of `Other`.
+    assert_eq!(program.unreachable_statements(), vec![StatementIdx(2), StatementIdx(5)]);
+}
+
+#[test]
+fn test_back_edges() {
+    let parser = ProgramParser::new();
+    let acyclic = parser
+        .parse(indoc! {"
+            libfunc jump = jump;
+            jump() { 1() };
+            jump() { 2() };
+            return();
+            Func@0() -> ();
+        "})
+        .unwrap();
+    assert_eq!(acyclic.back_edges(), vec![]);
+    assert!(!acyclic.has_cycle());
+
+    let cyclic = parser
+        .parse(indoc! {"
+            libfunc jump = jump;
+            libfunc felt_is_zero = felt_is_zero;
+            felt_is_zero(a) { fallthrough() 2(b) };
+            jump() { 0() };
+            return();
+            Func@0(a: felt) -> ();
+        "})
+        .unwrap();
+    assert_eq!(cyclic.back_edges(), vec![(StatementIdx(1), StatementIdx(0))]);
+    assert!(cyclic.has_cycle());
+}
+
+/// Returns the names of the variables live before each statement of the program, sorted.
+fn live_var_names(program: &Program) -> Vec<Vec<String>> {
+    program
+        .live_vars()
+        .into_iter()
+        .map(|live| live.into_iter().map(|var| var.to_string()).sorted().collect())
+        .collect()
+}
+
+#[test]
+fn test_live_vars_branching() {
+    let program = ProgramParser::new()
+        .parse(indoc! {"
+            libfunc felt_is_zero = felt_is_zero;
+            felt_is_zero(a) { fallthrough() 2(b) };
+            return(c);
+            return(d, b);
+            Func@0(a: felt, c: felt, d: felt) -> (felt);
+        "})
+        .unwrap();
+    // `b` is a result of the branch to statement 2, so it is not live before the invocation,
+    // which is live with the variables used by either branch.
+    assert_eq!(live_var_names(&program), vec![vec!["a", "c", "d"], vec!["c"], vec!["b", "d"]]);
+}
+
+#[test]
+fn test_live_vars_looping() {
+    let program = ProgramParser::new()
+        .parse(indoc! {"
+            libfunc felt_is_zero = felt_is_zero;
+            libfunc jump = jump;
+            felt_is_zero(a) { fallthrough() 2(b) };
+            jump() { 0() };
+            return(b, c);
+            Func@0(a: felt, c: felt) -> (felt);
+        "})
+        .unwrap();
+    // The variables live at the loop head are only propagated to the jump back to it by
+    // iterating to a fixpoint.
+    assert_eq!(live_var_names(&program), vec![vec!["a", "c"], vec!["a", "c"], vec!["b", "c"]]);
+}
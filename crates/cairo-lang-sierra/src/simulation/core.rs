@@ -11,7 +11,7 @@ use crate::extensions::array::ArrayConcreteLibfunc;
 use crate::extensions::boolean::BoolConcreteLibfunc;
 use crate::extensions::core::CoreConcreteLibfunc::{
     self, ApTracking, Array, Bitwise, Bool, BranchAlign, Drop, Dup, Ec, Enum, Felt, FunctionCall,
-    Gas, Mem, Struct, Uint128, Uint64, Uint8, UnconditionalJump, UnwrapNonZero,
+    Gas, Mem, SnapshotTake, Struct, Uint128, Uint64, Uint8, UnconditionalJump, UnwrapNonZero,
 };
 use crate::extensions::dict_felt_to::DictFeltToConcreteLibfunc;
 use crate::extensions::ec::EcConcreteLibfunc;
@@ -71,7 +71,7 @@ pub fn simulate<
             [_] => Ok((vec![], 0)),
             _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
         },
-        Dup(_) => match &inputs[..] {
+        Dup(_) | SnapshotTake(_) => match &inputs[..] {
             [value] => Ok((vec![value.clone(), value.clone()], 0)),
             _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
         },
@@ -237,7 +237,9 @@ pub fn simulate<
             _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
         },
         Struct(StructConcreteLibfunc::Construct(_)) => Ok((vec![CoreValue::Struct(inputs)], 0)),
-        Struct(StructConcreteLibfunc::Deconstruct(_)) => match &inputs[..] {
+        Struct(
+            StructConcreteLibfunc::Deconstruct(_) | StructConcreteLibfunc::SnapshotDeconstruct(_),
+        ) => match &inputs[..] {
             [CoreValue::Struct(_)] => {
                 // Extracting the values instead of cloning them, as the match is on a reference.
                 Ok((extract_matches!(inputs.into_iter().next().unwrap(), CoreValue::Struct), 0))
@@ -455,6 +457,13 @@ fn simulate_u128_libfunc(
             [_, _, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
             _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
         },
+        Uint128Concrete::WrappingAdd(_) => match inputs {
+            [CoreValue::RangeCheck, CoreValue::Uint128(lhs), CoreValue::Uint128(rhs)] => {
+                Ok((vec![CoreValue::RangeCheck, CoreValue::Uint128(lhs.wrapping_add(*rhs))], 0))
+            }
+            [_, _, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
         Uint128Concrete::WideMul(_) => match inputs {
             [CoreValue::RangeCheck, CoreValue::Uint128(lhs), CoreValue::Uint128(rhs)] => {
                 let result = BigInt::from(*lhs) * BigInt::from(*rhs);
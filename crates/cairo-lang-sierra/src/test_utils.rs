@@ -20,14 +20,24 @@ pub fn build_bijective_mapping() -> BiMap<ConcreteTypeId, ConcreteTypeLongId> {
     elements.insert("NonZeroU128".into(), as_type_long_id("NonZero", &["u128"]));
     elements.insert("ArrayFelt".into(), as_type_long_id("Array", &["felt"]));
     elements.insert("ArrayU128".into(), as_type_long_id("Array", &["u128"]));
+    elements.insert("BoxFelt".into(), as_type_long_id("Box", &["felt"]));
+    elements.insert("NullableFelt".into(), as_type_long_id("Nullable", &["felt"]));
     elements.insert("UninitializedFelt".into(), as_type_long_id("Uninitialized", &["felt"]));
     elements.insert("Uninitializedu128".into(), as_type_long_id("Uninitialized", &["u128"]));
     elements.insert("GasBuiltin".into(), as_type_long_id("GasBuiltin", &[]));
+    elements.insert("BuiltinCosts".into(), as_type_long_id("BuiltinCosts", &[]));
     elements.insert("RangeCheck".into(), as_type_long_id("RangeCheck", &[]));
     elements.insert("System".into(), as_type_long_id("System", &[]));
     elements.insert("StorageBaseAddress".into(), as_type_long_id("StorageBaseAddress", &[]));
     elements.insert("StorageAddress".into(), as_type_long_id("StorageAddress", &[]));
     elements.insert("ContractAddress".into(), as_type_long_id("ContractAddress", &[]));
+    elements.insert(
+        "CouponRegisteredFunction".into(),
+        ConcreteTypeLongId {
+            generic_id: "Coupon".into(),
+            generic_args: vec![GenericArg::UserFunc("RegisteredFunction".into())],
+        },
+    );
     elements
 }
 
@@ -24,6 +24,16 @@ fn parse(name: &str) {
     get_example_program(name);
 }
 
+#[test_case("fib_jumps")]
+#[test_case("fib_no_gas")]
+fn print_parse_round_trip(name: &str) {
+    let program = get_example_program(name);
+    assert_eq!(
+        cairo_lang_sierra::ProgramParser::new().parse(&program.to_string()).unwrap(),
+        program
+    );
+}
+
 #[test_case("fib_jumps")]
 #[test_case("fib_no_gas")]
 fn create_registry(name: &str) {
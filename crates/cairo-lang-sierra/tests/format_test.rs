@@ -1,3 +1,7 @@
+use cairo_lang_sierra::program::{
+    BranchInfo, BranchTarget, ConcreteLibfuncLongId, ConcreteTypeLongId, Function, Invocation,
+    LibfuncDeclaration, Param, Program, Statement, StatementIdx, TypeDeclaration,
+};
 use indoc::indoc;
 use test_log::test;
 
@@ -67,3 +71,56 @@ fn format_test() {
         .to_string())
     );
 }
+
+// Testing the display of a program built directly, with a branching invocation.
+#[test]
+fn format_built_program_test() {
+    let program = Program {
+        type_declarations: vec![TypeDeclaration {
+            id: "felt".into(),
+            long_id: ConcreteTypeLongId { generic_id: "felt".into(), generic_args: vec![] },
+        }],
+        libfunc_declarations: vec![LibfuncDeclaration {
+            id: "felt_is_zero".into(),
+            long_id: ConcreteLibfuncLongId {
+                generic_id: "felt_is_zero".into(),
+                generic_args: vec![],
+            },
+        }],
+        statements: vec![
+            Statement::Invocation(Invocation {
+                libfunc_id: "felt_is_zero".into(),
+                args: vec!["a".into()],
+                branches: vec![
+                    BranchInfo { target: BranchTarget::Fallthrough, results: vec![] },
+                    BranchInfo {
+                        target: BranchTarget::Statement(StatementIdx(2)),
+                        results: vec!["b".into()],
+                    },
+                ],
+            }),
+            Statement::Return(vec![]),
+            Statement::Return(vec![]),
+        ],
+        funcs: vec![Function::new(
+            "Foo".into(),
+            vec![Param { id: "a".into(), ty: "felt".into() }],
+            vec![],
+            StatementIdx(0),
+        )],
+    };
+    assert_eq!(
+        program.to_string(),
+        indoc! {"
+            type felt = felt;
+
+            libfunc felt_is_zero = felt_is_zero;
+
+            felt_is_zero(a) { fallthrough() 2(b) };
+            return();
+            return();
+
+            Foo@0(a: felt) -> ();
+        "}
+    );
+}
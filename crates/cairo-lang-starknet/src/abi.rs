@@ -1,5 +1,7 @@
-use cairo_lang_defs::ids::{TraitFunctionId, TraitId};
+use cairo_lang_defs::ids::{TopLevelLanguageElementId, TraitFunctionId, TraitId};
+use cairo_lang_lowering::db::LoweringGroup;
 use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::items::functions::ConcreteFunctionWithBodyId;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
@@ -101,6 +103,48 @@ impl Contract {
     }
 }
 
+/// ABI of a single function with a body, including the implicits it requires.
+///
+/// Implicits are not part of the external interface of a contract, so this is meant for
+/// non-contract tooling; [Contract] stays trait based.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FunctionAbi {
+    pub name: String,
+    pub inputs: Vec<Input>,
+    pub implicits: Vec<String>,
+    pub output_ty: String,
+}
+impl FunctionAbi {
+    /// Creates a function ABI from a ConcreteFunctionWithBodyId.
+    pub fn from_concrete_function(
+        db: &dyn LoweringGroup,
+        function: ConcreteFunctionWithBodyId,
+    ) -> Result<Self, ABIError> {
+        let semantic_db: &dyn SemanticGroup = db.upcast();
+        let function_with_body_id = function.function_with_body_id(semantic_db);
+        let signature = semantic_db
+            .concrete_function_signature(function.function_id(semantic_db))
+            .map_err(|_| ABIError::CompilationError)?;
+        let implicits = db
+            .function_with_body_all_implicits_vec(function_with_body_id)
+            .map_err(|_| ABIError::CompilationError)?;
+        Ok(Self {
+            name: function_with_body_id.name(semantic_db.upcast()).into(),
+            inputs: signature
+                .params
+                .into_iter()
+                .map(|param| Input { name: param.name.into(), ty: param.ty.format(semantic_db) })
+                .collect(),
+            implicits: implicits.into_iter().map(|ty| ty.format(semantic_db)).collect(),
+            output_ty: signature.return_type.format(semantic_db),
+        })
+    }
+
+    pub fn json(&self) -> String {
+        serde_json::to_string_pretty(&self).unwrap()
+    }
+}
+
 /// Checks whether the trait function has the given attribute.
 fn trait_function_has_attr(
     db: &dyn SemanticGroup,
@@ -1,11 +1,14 @@
+use cairo_lang_compiler::db::RootDatabase;
 use cairo_lang_defs::ids::ModuleItemId;
 use cairo_lang_semantic::db::SemanticGroup;
-use cairo_lang_semantic::test_utils::{setup_test_module, SemanticDatabaseForTesting};
+use cairo_lang_semantic::test_utils::{
+    setup_test_function, setup_test_module, SemanticDatabaseForTesting,
+};
 use cairo_lang_utils::extract_matches;
 use indoc::indoc;
 use pretty_assertions::assert_eq;
 
-use crate::abi::Contract;
+use crate::abi::{Contract, FunctionAbi};
 
 #[test]
 fn test_abi() {
@@ -106,3 +109,42 @@ fn test_abi() {
           ]"#}
     );
 }
+
+#[test]
+fn test_function_abi_with_implicits() {
+    let db = &mut RootDatabase::builder().detect_corelib().build().unwrap();
+    let test_function = setup_test_function(
+        db,
+        indoc! {"
+            fn foo(a: u128, b: u128) -> u128 {
+                a + b
+            }
+        "},
+        "foo",
+        "",
+    )
+    .unwrap();
+
+    let abi = FunctionAbi::from_concrete_function(db, test_function.concrete_function_id).unwrap();
+    assert_eq!(
+        abi.json(),
+        indoc! {
+        r#"{
+            "name": "foo",
+            "inputs": [
+              {
+                "name": "a",
+                "ty": "core::integer::u128"
+              },
+              {
+                "name": "b",
+                "ty": "core::integer::u128"
+              }
+            ],
+            "implicits": [
+              "core::RangeCheck"
+            ],
+            "output_ty": "core::integer::u128"
+          }"#}
+    );
+}
@@ -2,6 +2,7 @@
 #[path = "casm_contract_class_test.rs"]
 mod test;
 
+use cairo_lang_semantic::literals::{reduce_felt_value, FELT_PRIME};
 use cairo_lang_sierra::extensions::builtin_cost::CostTokenType;
 use cairo_lang_sierra::extensions::gas::GasBuiltinType;
 use cairo_lang_sierra::extensions::pedersen::PedersenType;
@@ -19,8 +20,7 @@ use cairo_lang_utils::unordered_hash_set::UnorderedHashSet;
 use convert_case::{Case, Casing};
 use itertools::chain;
 use num_bigint::BigUint;
-use num_integer::Integer;
-use num_traits::{Num, Signed};
+use num_traits::Num;
 use serde::ser::Serializer;
 use serde::{Deserialize, Deserializer, Serialize};
 use thiserror::Error;
@@ -68,11 +68,7 @@ impl CasmContractClass {
         contract_class: ContractClass,
     ) -> Result<Self, StarknetSierraCompilationError> {
         contract_class.verify_compatible_sierra_version()?;
-        let prime = BigUint::from_str_radix(
-            "800000000000011000000000000000000000000000000000000000000000001",
-            16,
-        )
-        .unwrap();
+        let prime = FELT_PRIME.to_biguint().unwrap();
 
         let program = sierra_from_felts(&contract_class.sierra_program)?;
 
@@ -102,12 +98,8 @@ impl CasmContractClass {
                     instruction.hints.iter().map(|hint| hint.to_string()).collect(),
                 ))
             }
-            bytecode.extend(instruction.assemble().encode().iter().map(|big_int| {
-                let (_q, reminder) = big_int.magnitude().div_rem(&prime);
-
-                BigIntAsHex {
-                    value: if big_int.is_negative() { &prime - reminder } else { reminder },
-                }
+            bytecode.extend(instruction.assemble().encode().iter().map(|big_int| BigIntAsHex {
+                value: reduce_felt_value(big_int).to_biguint().unwrap(),
             }))
         }
 
@@ -1,12 +1,20 @@
 use std::collections::HashMap;
 
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_compiler::CompilerConfig;
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_semantic::test_utils::setup_test_crate;
 use cairo_lang_test_utils::compare_contents_or_fix_with_path;
 use indoc::indoc;
 use num_bigint::BigUint;
 use pretty_assertions::assert_eq;
 use test_case::test_case;
 
-use crate::contract_class::{ContractClass, ContractEntryPoint, ContractEntryPoints};
+use crate::contract::find_contracts;
+use crate::contract_class::{
+    compile_prepared_db, ContractClass, ContractEntryPoint, ContractEntryPoints,
+};
+use crate::db::StarknetRootDatabaseBuilderEx;
 use crate::felt_serde::sierra_from_felts;
 use crate::test_utils::{get_example_file_path, get_test_contract};
 use crate::{abi, sierra_version};
@@ -62,6 +70,56 @@ fn test_serialization() {
     assert_eq!(contract, serde_json::from_str(&serialized).unwrap())
 }
 
+/// Tests that only the functions reachable from the entry points are compiled.
+#[test]
+fn test_unused_functions_are_removed() {
+    let db = &mut RootDatabase::builder().detect_corelib().with_starknet().build().unwrap();
+    setup_test_crate(
+        db,
+        indoc! {"
+            #[contract]
+            mod TestContract {
+                fn used_helper(a: felt) -> felt {
+                    a + 1
+                }
+
+                fn unused_helper(a: felt) -> felt {
+                    a + 2
+                }
+
+                #[external]
+                fn ep(a: felt) -> felt {
+                    used_helper(a)
+                }
+            }
+        "},
+    );
+    let contracts = find_contracts(db, &db.crates());
+    let contract_classes = compile_prepared_db(
+        db,
+        &contracts.iter().collect::<Vec<_>>(),
+        CompilerConfig { replace_ids: true, ..CompilerConfig::default() },
+    )
+    .unwrap();
+
+    let mut user_func_names = contract_classes[0]
+        .sierra_program_debug_info
+        .user_func_names
+        .values()
+        .map(|name| name.to_string())
+        .filter(|name| name.starts_with("test::"))
+        .collect::<Vec<_>>();
+    user_func_names.sort();
+    assert_eq!(
+        user_func_names,
+        vec![
+            "test::TestContract::__external::ep",
+            "test::TestContract::ep",
+            "test::TestContract::used_helper",
+        ]
+    );
+}
+
 #[test_case("test_contract")]
 #[test_case("hello_starknet")]
 #[test_case("erc20")]
@@ -81,6 +81,7 @@ pub fn get_spec() -> Vec<Node> {
     .add_enum(EnumBuilder::new("UnaryOperator")
         .node_with_explicit_kind("Not", "TerminalNot")
         .node_with_explicit_kind("Minus", "TerminalMinus")
+        .node_with_explicit_kind("At", "TerminalAt")
     )
     .add_struct(StructBuilder::new("ExprBinary")
         .node("lhs", "Expr")
@@ -102,6 +103,8 @@ pub fn get_spec() -> Vec<Node> {
         .node_with_explicit_kind("And", "TerminalAnd")
         .node_with_explicit_kind("Or", "TerminalOr")
         .node_with_explicit_kind("Xor", "TerminalXor")
+        .node_with_explicit_kind("AndAnd", "TerminalAndAnd")
+        .node_with_explicit_kind("OrOr", "TerminalOrOr")
         .node_with_explicit_kind("LE", "TerminalLE")
         .node_with_explicit_kind("GE", "TerminalGE")
         .node_with_explicit_kind("LT", "TerminalLT")
@@ -532,6 +535,7 @@ pub fn get_spec() -> Vec<Node> {
     .add_token_and_terminal("And")
     .add_token_and_terminal("AndAnd")
     .add_token_and_terminal("Arrow")
+    .add_token_and_terminal("At")
     .add_token_and_terminal("BadCharacters")
     .add_token_and_terminal("Colon")
     .add_token_and_terminal("ColonColon")
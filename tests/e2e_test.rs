@@ -19,6 +19,7 @@ cairo_lang_test_utils::test_file_test!(
         ec: "ec",
         get_gas_all: "get_gas_all",
         nullable: "nullable",
+        snapshot: "snapshot",
         u128: "u128",
         u8: "u8",
         u64: "u64",